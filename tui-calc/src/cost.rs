@@ -2,10 +2,83 @@ pub fn building(building_base_cost: f64, count: u16) -> f64 {
     building_base_cost * 1.15f64.powi(count as _)
 }
 
+// refunds a quarter of what the last one cost
 pub fn building_sell(cost: f64) -> f64 {
-    cost * (1.0 / 4.5)
+    cost / 1.15 * 0.25
+}
+
+// sum of the next `n` prices, built from `building` so that buying one costs exactly as much
+pub fn building_bulk(building_base_cost: f64, count: u16, n: u16) -> f64 {
+    (0..n)
+        .map(|i| building(building_base_cost, count.saturating_add(i)))
+        .sum()
+}
+
+// sum of the last `n` sell prices, built the same way
+pub fn building_bulk_sell(building_base_cost: f64, count: u16, n: u16) -> f64 {
+    (0..n.min(count))
+        .map(|i| building_sell(building(building_base_cost, count - i)))
+        .sum()
+}
+
+// adds up prices the same way `building_bulk` does, so the result is always affordable
+pub fn building_max_affordable(building_base_cost: f64, count: u16, cookies: f64) -> u16 {
+    let mut total = 0.0;
+    let mut n = 0;
+
+    while n < u16::MAX {
+        total += building(building_base_cost, count.saturating_add(n));
+        if total > cookies {
+            break;
+        }
+        n += 1;
+    }
+
+    n
 }
 
 pub fn elder_pledge(appeased_times: usize) -> f64 {
     64.0 * 8.0f64.powi(appeased_times.min(12) as _)
 }
+
+pub fn season_switcher(cps: f64, switches: usize) -> f64 {
+    1_000_000_000.0 + cps * 60.0 * 1.5f64.powi(switches.min(100) as _) * 1.5
+}
+
+pub fn easter_egg(eggs_owned: usize) -> f64 {
+    999.0 * 2.0f64.powi(eggs_owned as _)
+}
+
+pub fn santa_level(level: u8) -> f64 {
+    (level as f64).powi(level as _)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx_eq_trait::assert_approx_eq;
+
+    #[test]
+    fn from_wiki() {
+        assert_approx_eq!(building_bulk(15.0, 0, 1), building(15.0, 0));
+        assert_approx_eq!(building_bulk(15.0, 7, 1), building(15.0, 7));
+        assert!((building_bulk(15.0, 0, 10) - 304.5557735708).abs() < 1e-9);
+
+        assert_approx_eq!(
+            building_bulk_sell(15.0, 1, 1),
+            building_sell(building(15.0, 1))
+        );
+        assert_approx_eq!(
+            building_bulk_sell(15.0, 2, 2),
+            building_sell(building(15.0, 2)) + building_sell(building(15.0, 1))
+        );
+        assert_approx_eq!(
+            building_bulk_sell(15.0, 1, 5),
+            building_sell(building(15.0, 1))
+        );
+
+        assert_eq!(building_max_affordable(15.0, 0, 14.0), 0);
+        assert_eq!(building_max_affordable(15.0, 0, 304.6), 10);
+        assert_eq!(building_max_affordable(15.0, 0, 304.5), 9);
+    }
+}
@@ -1,15 +1,19 @@
 use crate::thousand_fingers::ThousandFingers;
 
-pub enum Cpc {
-    Basic,
-    ThousandFingers(ThousandFingers),
+pub struct Cpc {
+    pub thousand_fingers: Option<ThousandFingers>,
+    pub buffs_mult: f64,
+    pub garden_mult: f64,
 }
 
 impl Cpc {
     pub fn calc(self) -> f64 {
-        match self {
-            Self::Basic => 1.0,
-            Self::ThousandFingers(tf) => 1.0 + tf.calc(),
-        }
+        let cpc = match self.thousand_fingers {
+            Some(tf) => 1.0 + tf.calc(),
+            None => 1.0,
+        };
+
+        // The buffs and garden mults are pre-calculated.
+        cpc * self.buffs_mult * self.garden_mult
     }
 }
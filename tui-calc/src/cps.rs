@@ -7,22 +7,20 @@ pub mod kittens;
 pub struct Cps {
     pub base: f64,
     pub total: f64,
+    pub unbuffed: f64,
     pub wrinkled: f64,
     pub debuff_ratio: f64,
 }
 
 impl Cps {
-    pub fn new<BuildingCpses, GrandmapocalypseMults>(
-        base: base::Cps<BuildingCpses>,
-        addl: addl::Cps<GrandmapocalypseMults>,
-    ) -> Self
+    pub fn new<BuildingCpses>(base: base::Cps<BuildingCpses>, addl: addl::Cps) -> Self
     where
         BuildingCpses: Iterator<Item = f64>,
-        GrandmapocalypseMults: Iterator<Item = f64>,
     {
         let base = base.calc();
         let addl::Calced {
             cps: total,
+            unbuffed,
             wrinkled,
             debuff_ratio,
         } = addl.calc(base);
@@ -30,6 +28,7 @@ impl Cps {
         Self {
             base,
             total,
+            unbuffed,
             wrinkled,
             debuff_ratio,
         }
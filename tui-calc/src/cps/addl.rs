@@ -1,42 +1,70 @@
-pub struct Cps<GrandmapocalypseMults> {
-    pub grandmapocalypse_mults: GrandmapocalypseMults,
+pub struct Cps {
+    pub prestige_mult: f64,
+    pub heavenly_upgrades_mult: f64,
+    pub pantheon_mult: f64,
+    pub modifiers_mult: f64,
     pub kitten_mult: f64,
+    pub buffs_mult: f64,
+    pub garden_mult: f64,
+    pub santa_mult: f64,
+    pub dragon_mult: f64,
     pub wrinkler_count: usize,
     pub has_elder_covenant: bool,
 }
 
-impl<GrandmapocalypseMults> Cps<GrandmapocalypseMults>
-where
-    GrandmapocalypseMults: Iterator<Item = f64>,
-{
+impl Cps {
     pub fn calc(self, base: f64) -> Calced {
         let mut cps = base;
         let mut wrinkled = 0.0;
         let mut debuff_ratio = 0.0;
 
-        // Each grandmapocalypse mult is multiplied independantly.
-        // These are expected to be floats of the form 1.04, for a 4% increase.
-        for grandmapocalypse_mult in self.grandmapocalypse_mults {
-            cps *= grandmapocalypse_mult;
-        }
+        // The prestige and heavenly upgrade mults are pre-calculated.
+        cps *= self.prestige_mult;
+        cps *= self.heavenly_upgrades_mult;
+
+        // The pantheon mult is pre-calculated from the slotted spirits.
+        cps *= self.pantheon_mult;
+
+        // The modifiers mult is pre-calculated from the research,
+        // seasonal cookie and santa gift upgrades.
+        cps *= self.modifiers_mult;
 
         // The kitten mult is pre-calculated.
         cps *= self.kitten_mult;
 
+        // The garden mult is pre-calculated from mature plants.
+        cps *= self.garden_mult;
+
+        // The santa mult is pre-calculated from santa's legacy.
+        cps *= self.santa_mult;
+
+        // The dragon mult is pre-calculated from the slotted auras.
+        cps *= self.dragon_mult;
+
+        // The buffs mult is pre-calculated, and applies before
+        // wrinklers so that they also feast on frenzies.
+        // The unbuffed cps is kept alongside for anything
+        // that shouldn't swing with temporary buffs.
+        let mut unbuffed = cps;
+        cps *= self.buffs_mult;
+
         if self.wrinkler_count > 0 {
             let mult = 0.05 * self.wrinkler_count as f64;
 
             wrinkled = cps * mult;
             cps -= wrinkled;
+            unbuffed -= unbuffed * mult;
             debuff_ratio += mult;
         }
 
         if self.has_elder_covenant {
             cps *= 0.95;
+            unbuffed *= 0.95;
         }
 
         Calced {
             cps,
+            unbuffed,
             wrinkled,
             debuff_ratio,
         }
@@ -46,6 +74,7 @@ where
 #[non_exhaustive]
 pub struct Calced {
     pub cps: f64,
+    pub unbuffed: f64,
     pub wrinkled: f64,
     pub debuff_ratio: f64,
 }
@@ -1,4 +1,8 @@
-use crate::{thousand_fingers::ThousandFingers, upgrade::grandma_job_num_req_for_1p};
+use crate::{
+    dragon::elder_battalion_mult,
+    thousand_fingers::ThousandFingers,
+    upgrade::{grandma_job_num_req_for_1p, synergy_mult},
+};
 
 pub struct Cps {
     pub building_no: u16,
@@ -6,6 +10,13 @@ pub struct Cps {
     pub building_class: CpsClass,
     pub count: u16,
     pub tiered_upgrade_count: u16,
+    pub synergies: Vec<Synergy>,
+    pub level: u16,
+}
+
+pub struct Synergy {
+    pub partner_count: u16,
+    pub partner_is_higher_tier: bool,
 }
 
 pub enum CpsClass {
@@ -18,6 +29,7 @@ pub enum CpsClass {
         has_one_mind: bool,
         has_communal_brainsweep: bool,
         elder_pact_portal_count: Option<u16>,
+        elder_battalion_building_count: Option<u16>,
         job_upgrade_count: u16,
     },
     Other {
@@ -33,6 +45,8 @@ impl Cps {
             building_class,
             count,
             tiered_upgrade_count,
+            synergies,
+            level,
         } = self;
 
         let cps = building_base_cps * count as f64 * 2.0f64.powi(tiered_upgrade_count as i32);
@@ -50,11 +64,13 @@ impl Cps {
                 has_one_mind,
                 has_communal_brainsweep,
                 elder_pact_portal_count,
+                elder_battalion_building_count,
                 job_upgrade_count,
             } => {
                 cps * if has_bingo_center { 4.0 } else { 1.0 }
                     * if has_ritual_rolling_pins { 2.0 } else { 1.0 }
                     * 2.0f64.powi(job_upgrade_count as i32)
+                    * elder_battalion_building_count.map_or(1.0, elder_battalion_mult)
                     + if has_one_mind {
                         0.02 * count as f64
                     } else {
@@ -89,6 +105,14 @@ impl Cps {
             }
         };
 
+        // Each synergy boosts the building by how many of its partner are owned.
+        let cps = synergies.iter().fold(cps, |cps, synergy| {
+            cps * synergy_mult(synergy.partner_count, synergy.partner_is_higher_tier)
+        });
+
+        // Each level is a flat +1%, applied after everything else.
+        let cps = cps * (1.0 + 0.01 * level as f64);
+
         cps
     }
 }
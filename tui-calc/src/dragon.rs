@@ -0,0 +1,24 @@
+pub fn boosted_mult(mult: f64, boost: f64) -> f64 {
+    1.0 + (mult - 1.0) * (1.0 + boost)
+}
+
+pub fn elder_battalion_mult(non_grandma_buildings_count: u16) -> f64 {
+    1.0 + 0.01 * non_grandma_buildings_count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx_eq_trait::assert_approx_eq;
+
+    #[test]
+    fn from_wiki() {
+        // breath of milk, 100% milk with kitten helpers
+        assert_approx_eq!(boosted_mult(1.1, 0.05), 1.105);
+        // no boost when there's nothing to boost
+        assert_approx_eq!(boosted_mult(1.0, 0.05), 1.0);
+
+        assert_approx_eq!(elder_battalion_mult(0), 1.0);
+        assert_approx_eq!(elder_battalion_mult(250), 3.5);
+    }
+}
@@ -0,0 +1,31 @@
+pub fn seed_cost(cps: f64, cost_mins: f64) -> f64 {
+    f64::max(cps * 60.0 * cost_mins, 60.0 * cost_mins)
+}
+
+pub fn harvest_cookies(cookies: f64, cps: f64, cps_mins: f64, bank_ratio: f64) -> f64 {
+    f64::min(cps * 60.0 * cps_mins, cookies * bank_ratio)
+}
+
+pub fn effect_mult(mult: f64, soil_mult: f64) -> f64 {
+    1.0 + (mult - 1.0) * soil_mult
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx_eq_trait::assert_approx_eq;
+
+    #[test]
+    fn from_wiki() {
+        // baker's wheat costs 1 minute of cps, and at least 60 cookies
+        assert_approx_eq!(seed_cost(10.0, 1.0), 600.0);
+        assert_approx_eq!(seed_cost(0.1, 1.0), 60.0);
+
+        // bakeberry: 30 minutes of cps, up to 3% of the bank
+        assert_approx_eq!(harvest_cookies(1_000_000.0, 10.0, 30.0, 0.03), 18000.0);
+        assert_approx_eq!(harvest_cookies(100_000.0, 10.0, 30.0, 0.03), 3000.0);
+
+        // clay boosts effects by 25%
+        assert_approx_eq!(effect_mult(1.01, 1.25), 1.0125);
+    }
+}
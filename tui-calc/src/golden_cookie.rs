@@ -0,0 +1,46 @@
+pub fn lucky_cookies(cookies: f64, cps: f64) -> f64 {
+    f64::min(cookies * 0.15, cps * 60.0 * 15.0) + 13.0
+}
+
+pub fn building_special_mult(count: u16) -> f64 {
+    1.0 + 0.1 * count as f64
+}
+
+pub fn reindeer_cookies(cps: f64) -> f64 {
+    f64::max(25.0, cps * 60.0)
+}
+
+pub fn ruin_cookies(cookies: f64, cps: f64) -> f64 {
+    f64::min(cookies * 0.05, cps * 60.0 * 10.0) + 13.0
+}
+
+pub fn cursed_finger_cpc(cps: f64, duration_secs: f64) -> f64 {
+    cps * duration_secs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx_eq_trait::assert_approx_eq;
+
+    #[test]
+    fn from_wiki() {
+        // bank-limited
+        assert_approx_eq!(lucky_cookies(1000.0, 10.0), 163.0);
+        // cps-limited
+        assert_approx_eq!(lucky_cookies(1_000_000.0, 10.0), 9013.0);
+
+        assert_approx_eq!(building_special_mult(10), 2.0);
+        assert_approx_eq!(building_special_mult(150), 16.0);
+
+        assert_approx_eq!(reindeer_cookies(0.1), 25.0);
+        assert_approx_eq!(reindeer_cookies(10.0), 600.0);
+
+        // bank-limited
+        assert_approx_eq!(ruin_cookies(1000.0, 10.0), 63.0);
+        // cps-limited
+        assert_approx_eq!(ruin_cookies(1_000_000.0, 10.0), 6013.0);
+
+        assert_approx_eq!(cursed_finger_cpc(10.0, 10.0), 100.0);
+    }
+}
@@ -0,0 +1,55 @@
+pub fn max_magic(towers: u16, level: u16) -> f64 {
+    if towers == 0 {
+        return 0.0;
+    }
+
+    let towers = towers as f64;
+    let level = level.saturating_sub(1) as f64;
+
+    let magic = 4.0 + towers.powf(0.6) + 15.0 * (1.0 + (towers + 10.0 * level) / 15.0).ln();
+    magic.floor()
+}
+
+pub fn magic_regen_per_sec(magic: f64, max_magic: f64) -> f64 {
+    // The original regenerates this much per frame, at 30 frames per second.
+    let per_frame = f64::max(0.002, (magic / f64::max(max_magic, 100.0)).sqrt()) * 0.002;
+    per_frame * 30.0
+}
+
+pub fn spell_cost(base: f64, percent: f64, max_magic: f64) -> f64 {
+    base + percent * max_magic
+}
+
+pub fn backfire_chance(base: f64, cost: f64, max_magic: f64) -> f64 {
+    // Draining a bigger share of the meter makes the spell less stable.
+    f64::min(1.0, base + 0.15 * cost / f64::max(max_magic, 1.0))
+}
+
+pub fn conjure_baked_goods_cookies(cookies: f64, cps: f64) -> f64 {
+    f64::min(cookies * 0.15, cps * 60.0 * 30.0)
+}
+
+pub fn conjure_baked_goods_backfire_cookies(cookies: f64, cps: f64) -> f64 {
+    f64::min(cookies * 0.15, cps * 60.0 * 15.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx_eq_trait::assert_approx_eq;
+
+    #[test]
+    fn from_wiki() {
+        assert_approx_eq!(max_magic(0, 1), 0.0);
+        assert_approx_eq!(max_magic(1, 1), 5.0);
+        assert_approx_eq!(max_magic(100, 1), 50.0);
+        assert_approx_eq!(max_magic(300, 10), 84.0);
+
+        // force the hand of fate: 10 magic + 60% of max magic
+        assert_approx_eq!(spell_cost(10.0, 0.6, 50.0), 40.0);
+
+        // conjure baked goods: 30 minutes of cps, up to 15% of the bank
+        assert_approx_eq!(conjure_baked_goods_cookies(1_000_000.0, 10.0), 18000.0);
+        assert_approx_eq!(conjure_baked_goods_cookies(1000.0, 10.0), 150.0);
+    }
+}
@@ -3,6 +3,12 @@
 pub mod cost;
 pub mod cpc;
 pub mod cps;
+pub mod dragon;
+pub mod garden;
+pub mod golden_cookie;
+pub mod grimoire;
+pub mod prestige;
+pub mod sugar_lump;
 pub mod thousand_fingers;
 pub mod upgrade;
 pub mod wrinkler;
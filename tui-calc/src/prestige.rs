@@ -0,0 +1,23 @@
+pub fn level(cookies_all_time: f64) -> f64 {
+    (cookies_all_time / 1e12).cbrt().floor()
+}
+
+pub fn cps_mult(level: f64) -> f64 {
+    1.0 + 0.01 * level
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx_eq_trait::assert_approx_eq;
+
+    #[test]
+    fn from_wiki() {
+        assert_approx_eq!(level(999e9), 0.0);
+        assert_approx_eq!(level(1e12), 1.0);
+        assert_approx_eq!(level(7.9e12), 1.0);
+        assert_approx_eq!(level(8e12), 2.0);
+        assert_approx_eq!(level(1e18), 100.0);
+        assert_approx_eq!(cps_mult(100.0), 2.0);
+    }
+}
@@ -0,0 +1,4 @@
+pub fn ripening_mult(grandmas_count: u16) -> f64 {
+    // Each grandma shaves 6 seconds off a 24 hour lump, up to an hour total.
+    1.0 - grandmas_count.min(600) as f64 * 6.0 / (24.0 * 60.0 * 60.0)
+}
@@ -7,3 +7,23 @@ pub fn grandma_job_num_req_for_1p(building_no: u16) -> u16 {
     // ...
     building_no - 1
 }
+
+// the lower tier building gains 5% per higher tier building,
+// the higher tier one gains 0.1% per lower tier building
+pub fn synergy_mult(partner_count: u16, partner_is_higher_tier: bool) -> f64 {
+    let per = if partner_is_higher_tier { 0.05 } else { 0.001 };
+    1.0 + per * partner_count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx_eq_trait::assert_approx_eq;
+
+    #[test]
+    fn from_wiki() {
+        // future almanacs, 10 farms and 20 time machines
+        assert_approx_eq!(synergy_mult(20, true), 2.0);
+        assert_approx_eq!(synergy_mult(10, false), 1.01);
+    }
+}
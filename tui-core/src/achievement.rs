@@ -1,5 +1,5 @@
 use crate::{
-    Building, Computed, State, macros,
+    Building, Computed, Season, State, macros,
     req::{Cmp, LateReq},
 };
 use cookie_clicker_tui_utils::{num, refresh::Refresh};
@@ -20,6 +20,11 @@ pub fn tick(state: &mut State, computed: &Computed) {
                 continue;
             }
 
+            // automation would earn these for free
+            if state.achievements.cheated() && achievement.req().automatable() {
+                continue;
+            }
+
             if achievement.req().into_late_req().check(state, computed) {
                 state.achievements.owned.insert(achievement);
                 state.achievements.display_queue.push_back(achievement);
@@ -28,7 +33,7 @@ pub fn tick(state: &mut State, computed: &Computed) {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Achievements {
     owned: BTreeSet<Achievement>,
     display_queue: VecDeque<Achievement>,
@@ -55,6 +60,17 @@ impl Achievements {
     pub fn queued(&self) -> Option<Achievement> {
         self.display_queue.front().copied()
     }
+
+    pub fn cheated(&self) -> bool {
+        self.owned.contains(&Achievement::CheatedCookiesTasteAwful)
+    }
+
+    pub fn mark_cheated(&mut self) {
+        if self.owned.insert(Achievement::CheatedCookiesTasteAwful) {
+            self.display_queue
+                .push_back(Achievement::CheatedCookiesTasteAwful);
+        }
+    }
 }
 
 macros::serialize_via_state!(Achievements => BTreeSet<Achievement> as |a| a.owned);
@@ -688,6 +704,15 @@ pub enum Achievement {
     ManafestDestiny,
     // TODO: The rest.
     /* -------------------------------------------------------------------------- */
+    /*                                Buildings Sold                              */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(req = AchievementReq::BuildingsSold(100))]
+    Liquidation,
+    #[assoc(req = AchievementReq::BuildingsSold(1_000))]
+    ClearanceSale,
+    #[assoc(req = AchievementReq::BuildingsSold(10_000))]
+    EverythingMustGo,
+    /* -------------------------------------------------------------------------- */
     /*                               Golden Cookies                               */
     /* -------------------------------------------------------------------------- */
     #[assoc(req = AchievementReq::GoldenCookieClickedCount(1))]
@@ -702,6 +727,10 @@ pub enum Achievement {
     Leprechaun,
     #[assoc(req = AchievementReq::GoldenCookieClickedCount(7_777))]
     BlackCatsPaw,
+    #[assoc(req = AchievementReq::GoldenCookieClickedCount(27_777))]
+    SevenHorseshoes,
+    #[assoc(req = AchievementReq::GoldenCookiesOnScreen(4))]
+    FourLeafCookie,
     #[assoc(req = AchievementReq::GoldenCookieClickedAtMost1sAfterSpawn)]
     EarlyBird,
     #[assoc(req = AchievementReq::GoldenCookieClickedAtMost1sBeforeDespawn)]
@@ -711,7 +740,8 @@ pub enum Achievement {
     /* -------------------------------------------------------------------------- */
     #[assoc(req = AchievementReq::GrandmapocalypseStarted)]
     Grandmapocalypse,
-    // WrathCookie // TODO
+    #[assoc(req = AchievementReq::WrathCookieClickedCount(1))]
+    WrathCookie,
     #[assoc(req = AchievementReq::GrandmapocalypseTemporarilyAppeasedTimes(1))]
     ElderNap,
     #[assoc(req = AchievementReq::GrandmapocalypseTemporarilyAppeasedTimes(5))]
@@ -727,6 +757,30 @@ pub enum Achievement {
     Moistburster,
     #[assoc(req = AchievementReq::ShinyWrinklerPopped)]
     LastChanceToSee,
+    /* -------------------------------------------------------------------------- */
+    /*                                   Seasons                                  */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(req = AchievementReq::SeasonDropsAll(Season::Halloween))]
+    SpookyCookies,
+    #[assoc(req = AchievementReq::SeasonDropsAll(Season::Christmas))]
+    LetItSnow,
+    #[assoc(req = AchievementReq::ReindeerClicked(1))]
+    OhDeer,
+    #[assoc(req = AchievementReq::ReindeerClicked(50))]
+    SleighOfHand,
+    #[assoc(req = AchievementReq::ReindeerClicked(200))]
+    ReindeerSleigher,
+    #[assoc(req = AchievementReq::SeasonDropsAll(Season::Valentines))]
+    LovelyCookies,
+    #[assoc(req = AchievementReq::SeasonDrops(Season::Easter, 1))]
+    TheHuntIsOn,
+    #[assoc(req = AchievementReq::SeasonDrops(Season::Easter, 7))]
+    EggingOn,
+    /* -------------------------------------------------------------------------- */
+    /*                                   Shadow                                   */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(req = AchievementReq::Cheated)]
+    CheatedCookiesTasteAwful,
 }
 
 pub enum AchievementReq {
@@ -736,22 +790,41 @@ pub enum AchievementReq {
     BuildingCombinedCount(Building, Building, u16),
     BuildingCookiesBaked(Building, f64),
     SellAGrandma,
+    BuildingsSold(u32),
     Cps(f64),
     GrandmaJobCount(u16),
     GoldenCookieClickedCount(usize),
     GoldenCookieClickedAtMost1sAfterSpawn,
     GoldenCookieClickedAtMost1sBeforeDespawn,
+    GoldenCookiesOnScreen(usize),
+    WrathCookieClickedCount(usize),
     GrandmapocalypseStarted,
     GrandmapocalypseTemporarilyAppeasedTimes(usize),
     GrandmapocalypsePermanentlyAppeasedEver,
     WrinklersPopped(usize),
     ShinyWrinklerPopped,
+    ReindeerClicked(usize),
+    SeasonDrops(Season, usize),
+    SeasonDropsAll(Season),
+    Cheated,
 }
 
 impl AchievementReq {
+    pub fn automatable(&self) -> bool {
+        matches!(
+            self,
+            Self::CookiesBakedFromClicking(_)
+                | Self::GoldenCookieClickedCount(_)
+                | Self::GoldenCookieClickedAtMost1sAfterSpawn
+                | Self::GoldenCookieClickedAtMost1sBeforeDespawn
+                | Self::WrathCookieClickedCount(_)
+                | Self::ReindeerClicked(_)
+        )
+    }
+
     fn into_late_req(self) -> LateReq {
         match self {
-            Self::CookiesBaked(v) => LateReq::CookiesAllTime(Cmp::AboveOrEq(v)),
+            Self::CookiesBaked(v) => LateReq::CookiesAllRuns(Cmp::AboveOrEq(v)),
             Self::CookiesBakedFromClicking(v) => {
                 LateReq::CookiesAllTimeFromClicking(Cmp::AboveOrEq(v))
             }
@@ -765,6 +838,9 @@ impl AchievementReq {
                 LateReq::BuildingCookiesAllTime(b, Cmp::AboveOrEq(v))
             }
             Self::SellAGrandma => LateReq::Custom(|state, _| state.buildings.grandma_been_sold()),
+            Self::BuildingsSold(v) => {
+                LateReq::CustomBox(Box::new(move |state, _| state.buildings.total_sold() >= v))
+            }
             Self::Cps(v) => LateReq::Cps(Cmp::AboveOrEq(v)),
             Self::GrandmaJobCount(v) => LateReq::GrandmaJobUpgradeCount(Cmp::AboveOrEq(v)),
             Self::GoldenCookieClickedCount(v) => LateReq::GoldenCookieClicked(Cmp::AboveOrEq(v)),
@@ -774,6 +850,12 @@ impl AchievementReq {
             Self::GoldenCookieClickedAtMost1sBeforeDespawn => {
                 LateReq::GoldenCookieClickedAtMost1sBeforeDespawn()
             }
+            Self::GoldenCookiesOnScreen(v) => LateReq::CustomBox(Box::new(move |state, _| {
+                state.golden_cookies.max_on_screen() >= v
+            })),
+            Self::WrathCookieClickedCount(v) => LateReq::CustomBox(Box::new(move |state, _| {
+                state.golden_cookies.wrath_click_count() >= v
+            })),
             Self::GrandmapocalypseStarted => LateReq::GrandmapocalypsePhaseAny(),
             Self::GrandmapocalypseTemporarilyAppeasedTimes(v) => {
                 LateReq::GrandmapocalypseTemporarilyAppeasedTimes(Cmp::AboveOrEq(v))
@@ -785,6 +867,17 @@ impl AchievementReq {
             Self::ShinyWrinklerPopped => {
                 LateReq::Custom(|state, _| state.grandmapocalypse.wrinklers().popped_shiny_ever())
             }
+            Self::ReindeerClicked(v) => LateReq::CustomBox(Box::new(move |state, _| {
+                state.golden_cookies.reindeer_click_count() >= v
+            })),
+            Self::SeasonDrops(season, v) => LateReq::CustomBox(Box::new(move |state, _| {
+                state.seasons.dropped_count(season) >= v
+            })),
+            Self::SeasonDropsAll(season) => {
+                LateReq::CustomBox(Box::new(move |state, _| state.seasons.dropped_all(season)))
+            }
+            // only ever given out by marking the core as cheated
+            Self::Cheated => LateReq::Custom(|_, _| false),
         }
     }
 }
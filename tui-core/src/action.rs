@@ -0,0 +1,111 @@
+use crate::{
+    Building, Core, DragonAura, DragonAuraSlot, Good, HeavenlyUpgrade, Seed, Soil, Spell, Spirit,
+    SpiritSlot, Upgrade,
+};
+use serde::{Deserialize, Serialize};
+
+// everything a player can do to the core
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(tag = "action")]
+pub enum Action {
+    ClickCookie,
+    ClickGoldenCookie {
+        ch: char,
+    },
+    BuyBuilding {
+        building: Building,
+        count: u16,
+    },
+    SellBuilding {
+        building: Building,
+        count: u16,
+    },
+    LevelUpBuilding {
+        building: Building,
+    },
+    BuyUpgrade {
+        upgrade: Upgrade,
+    },
+    PopWrinkler {
+        index: usize,
+    },
+    PopAllWrinklers,
+    HarvestSugarLump,
+    Ascend,
+    BuyHeavenlyUpgrade {
+        upgrade: HeavenlyUpgrade,
+    },
+    BuyStock {
+        good: Good,
+        count: u16,
+    },
+    SellStock {
+        good: Good,
+        count: u16,
+    },
+    CastSpell {
+        spell: Spell,
+    },
+    SlotSpirit {
+        slot: SpiritSlot,
+        spirit: Option<Spirit>,
+    },
+    PlantGardenSeed {
+        x: usize,
+        y: usize,
+        seed: Seed,
+    },
+    HarvestGardenPlant {
+        x: usize,
+        y: usize,
+    },
+    SetGardenSoil {
+        soil: Soil,
+    },
+    LevelUpSanta,
+    TrainDragon,
+    SetDragonAura {
+        slot: DragonAuraSlot,
+        aura: Option<DragonAura>,
+    },
+}
+
+impl Action {
+    // returns whether the action went through
+    pub(crate) fn apply(self, core: &mut Core) -> bool {
+        match self {
+            Self::ClickCookie => {
+                core.click_cookie();
+                true
+            }
+            Self::ClickGoldenCookie { ch } => core.click_golden_cookie(ch),
+            Self::BuyBuilding { building, count } => core.buy_building_n(building, count),
+            Self::SellBuilding { building, count } => core.sell_building_n(building, count),
+            Self::LevelUpBuilding { building } => core.level_up_building(building),
+            Self::BuyUpgrade { upgrade } => core.buy_upgrade(upgrade),
+            Self::PopWrinkler { index } => {
+                let len = core.grandmapocalypse().wrinklers().len();
+                core.pop_wrinkler(index);
+                core.grandmapocalypse().wrinklers().len() < len
+            }
+            Self::PopAllWrinklers => {
+                let empty = core.grandmapocalypse().wrinklers().is_empty();
+                core.pop_all_wrinklers();
+                !empty
+            }
+            Self::HarvestSugarLump => core.harvest_sugar_lump().is_some(),
+            Self::Ascend => core.ascend(),
+            Self::BuyHeavenlyUpgrade { upgrade } => core.buy_heavenly_upgrade(upgrade),
+            Self::BuyStock { good, count } => core.buy_stock(good, count),
+            Self::SellStock { good, count } => core.sell_stock(good, count),
+            Self::CastSpell { spell } => core.cast_spell(spell).is_some(),
+            Self::SlotSpirit { slot, spirit } => core.slot_spirit(slot, spirit),
+            Self::PlantGardenSeed { x, y, seed } => core.plant_garden_seed(x, y, seed),
+            Self::HarvestGardenPlant { x, y } => core.harvest_garden_plant(x, y).is_some(),
+            Self::SetGardenSoil { soil } => core.set_garden_soil(soil),
+            Self::LevelUpSanta => core.level_up_santa(),
+            Self::TrainDragon => core.train_dragon(),
+            Self::SetDragonAura { slot, aura } => core.set_dragon_aura(slot, aura),
+        }
+    }
+}
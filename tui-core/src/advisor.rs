@@ -0,0 +1,149 @@
+use crate::{Building, Changeset, CostResolved, State, Upgrade, calc, cps::Cps};
+use cookie_clicker_tui_utils::refresh::Refresh;
+use std::ops::Deref;
+
+// how far ahead a building counts as affordable soon, at the current cps
+const AFFORDABLE_SOON_SECS: f64 = 15.0 * 60.0;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Purchase {
+    Building(Building),
+    Upgrade(Upgrade),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PurchaseAdvice {
+    purchase: Purchase,
+    cost: f64,
+    delta_cps: f64,
+    payback_secs: f64,
+}
+
+impl PurchaseAdvice {
+    pub fn purchase(&self) -> Purchase {
+        self.purchase
+    }
+
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+
+    pub fn delta_cps(&self) -> f64 {
+        self.delta_cps
+    }
+
+    // the wait until it's affordable plus the time the extra cps takes to earn the cost back
+    pub fn payback_secs(&self) -> f64 {
+        self.payback_secs
+    }
+}
+
+// purchases sorted by payback time, the first one is the best buy
+pub struct PurchaseAdvisor {
+    list: Box<[PurchaseAdvice]>,
+    refresh: Refresh,
+}
+
+impl PurchaseAdvisor {
+    pub fn new(state: &State, cps: &Cps, available_upgrades: &[Upgrade]) -> Self {
+        let cookies = state.cookies.current();
+        // buffs are left out so that advice doesn't swing during a frenzy or clot
+        let base_cps = calc::cps(state).unbuffed;
+        let affordable_soon = cookies + base_cps * AFFORDABLE_SOON_SECS;
+
+        let buildings = state.buildings.infos().filter_map(|info| {
+            let purchase = Purchase::Building(info.building());
+            match info.cost().resolve(state, cps) {
+                CostResolved::Cookies(c) if c <= affordable_soon => Some((purchase, c)),
+                _ => None,
+            }
+        });
+
+        let upgrades = available_upgrades.iter().filter_map(|upgrade| {
+            let purchase = Purchase::Upgrade(*upgrade);
+            match upgrade.resolved_cost(state, cps) {
+                CostResolved::Cookies(c) => Some((purchase, c)),
+                _ => None,
+            }
+        });
+
+        let mut v = buildings
+            .chain(upgrades)
+            .filter_map(|(purchase, cost)| {
+                let delta_cps = simulate(state, purchase) - base_cps;
+                if delta_cps <= 0.0 {
+                    return None;
+                }
+
+                // without any cps, the wait for something unaffordable never ends
+                let wait_secs = if cost <= cookies {
+                    0.0
+                } else if base_cps > 0.0 {
+                    (cost - cookies) / base_cps
+                } else {
+                    return None;
+                };
+
+                Some(PurchaseAdvice {
+                    purchase,
+                    cost,
+                    delta_cps,
+                    payback_secs: wait_secs + cost / delta_cps,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        v.sort_by(|a, b| f64::total_cmp(&a.payback_secs, &b.payback_secs));
+
+        Self {
+            list: v.into(),
+            refresh: Refresh::new(3.0),
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        state: &State,
+        cps: &Cps,
+        available_upgrades: &[Upgrade],
+        changeset: &Changeset,
+    ) {
+        if self.refresh.finish() || changeset.cps || changeset.available_upgrades {
+            *self = Self::new(state, cps, available_upgrades);
+        }
+    }
+
+    pub fn best(&self) -> Option<Purchase> {
+        self.list.first().map(|a| a.purchase)
+    }
+}
+
+impl Deref for PurchaseAdvisor {
+    type Target = [PurchaseAdvice];
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+// the cps after making the purchase on a copy of the state
+fn simulate(state: &State, purchase: Purchase) -> f64 {
+    let mut state = state.clone();
+    let mut changeset = Changeset::default();
+
+    match purchase {
+        Purchase::Building(building) => {
+            state
+                .buildings
+                .modify_count(building, |c| *c += 1, &mut changeset);
+        }
+        Purchase::Upgrade(upgrade) => {
+            if upgrade.should_add_to_owned() {
+                state.owned_upgrades.add(upgrade);
+            }
+            upgrade.buy(&mut state, &mut changeset);
+        }
+    }
+
+    calc::cps(&state).unbuffed
+}
@@ -0,0 +1,178 @@
+use crate::{Building, Changeset};
+use cookie_clicker_tui_utils::{frames::FPS, refresh::Refresh};
+use serde::{Deserialize, Serialize};
+
+const HAGGLERS_UPGRADE_COST_MULT: f64 = 0.98;
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(transparent)]
+pub struct Buffs {
+    list: Vec<Buff>,
+}
+
+impl Buffs {
+    pub(crate) fn new() -> Self {
+        Self { list: Vec::new() }
+    }
+
+    pub(crate) fn tick(&mut self, changeset: &mut Changeset) {
+        let len_before = self.list.len();
+        self.list.retain_mut(|buff| !buff.refresh.finish());
+
+        if self.list.len() != len_before {
+            changeset.cps = true;
+        }
+    }
+
+    pub(crate) fn fast_forward_secs(&mut self, secs: f64) {
+        for buff in &mut self.list {
+            buff.refresh.fast_forward_secs(secs);
+        }
+    }
+
+    pub(crate) fn add(&mut self, buff: Buff, changeset: &mut Changeset) {
+        self.list.retain(|b| b.source != buff.source);
+        self.list.push(buff);
+        changeset.cps = true;
+    }
+
+    // scales the time left on every buff, capped at `max_extra_secs` when extending
+    pub(crate) fn stretch(&mut self, mult: f64, max_extra_secs: f64) {
+        for buff in &mut self.list {
+            let left = buff.refresh.until_finish();
+            let extra = f64::min(left * (mult - 1.0), max_extra_secs * FPS);
+            buff.refresh.modify(|max| *max += extra);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Buff> {
+        self.list.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn cps_mult(&self) -> f64 {
+        self.list.iter().map(|b| b.cps_mult).product()
+    }
+
+    pub fn cpc_mult(&self) -> f64 {
+        self.list.iter().map(|b| b.cpc_mult).product()
+    }
+
+    pub fn cpc_gain(&self) -> f64 {
+        self.list.iter().map(|b| b.cpc_gain).sum()
+    }
+
+    pub fn upgrade_cost_mult(&self) -> f64 {
+        self.list
+            .iter()
+            .map(|b| match b.source {
+                BuffSource::HagglersLuck => HAGGLERS_UPGRADE_COST_MULT,
+                BuffSource::HagglersMisery => 1.0 / HAGGLERS_UPGRADE_COST_MULT,
+                _ => 1.0,
+            })
+            .product()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Buff {
+    source: BuffSource,
+    cps_mult: f64,
+    cpc_mult: f64,
+    #[serde(default)]
+    cpc_gain: f64,
+    refresh: Refresh,
+}
+
+impl Buff {
+    pub(crate) fn cps(source: BuffSource, mult: f64, duration_secs: f64) -> Self {
+        Self {
+            source,
+            cps_mult: mult,
+            cpc_mult: 1.0,
+            cpc_gain: 0.0,
+            refresh: Refresh::new(duration_secs),
+        }
+    }
+
+    pub(crate) fn effect(source: BuffSource, duration_secs: f64) -> Self {
+        Self {
+            source,
+            cps_mult: 1.0,
+            cpc_mult: 1.0,
+            cpc_gain: 0.0,
+            refresh: Refresh::new(duration_secs),
+        }
+    }
+
+    pub(crate) fn cpc(source: BuffSource, mult: f64, duration_secs: f64) -> Self {
+        Self {
+            source,
+            cps_mult: 1.0,
+            cpc_mult: mult,
+            cpc_gain: 0.0,
+            refresh: Refresh::new(duration_secs),
+        }
+    }
+
+    // production stops, but every click is worth a flat amount of cookies
+    pub(crate) fn cps_into_cpc(source: BuffSource, cpc_gain: f64, duration_secs: f64) -> Self {
+        Self {
+            source,
+            cps_mult: 0.0,
+            cpc_mult: 1.0,
+            cpc_gain,
+            refresh: Refresh::new(duration_secs),
+        }
+    }
+
+    pub fn source(&self) -> BuffSource {
+        self.source
+    }
+
+    pub fn cps_mult(&self) -> f64 {
+        self.cps_mult
+    }
+
+    pub fn cpc_mult(&self) -> f64 {
+        self.cpc_mult
+    }
+
+    pub fn cpc_gain(&self) -> f64 {
+        self.cpc_gain
+    }
+
+    pub fn secs_left(&self) -> f64 {
+        self.refresh.until_finish_secs()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BuffSource {
+    Frenzy,
+    ClickFrenzy,
+    BuildingSpecial(Building),
+    HagglersLuck,
+    HagglersMisery,
+    Clot,
+    ElderFrenzy,
+    CursedFinger,
+}
+
+impl BuffSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Frenzy => "Frenzy",
+            Self::ClickFrenzy => "Click frenzy",
+            Self::BuildingSpecial(_) => "Building special",
+            Self::HagglersLuck => "Haggler's luck",
+            Self::HagglersMisery => "Haggler's misery",
+            Self::Clot => "Clot",
+            Self::ElderFrenzy => "Elder frenzy",
+            Self::CursedFinger => "Cursed finger",
+        }
+    }
+}
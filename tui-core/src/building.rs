@@ -5,6 +5,7 @@ use enum_fun::{Name, Predicates, Variants};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+#[derive(Clone)]
 pub struct Buildings {
     state: BuildingsState,
     computed: BuildingsComputed,
@@ -68,6 +69,22 @@ impl Buildings {
         self.state.flags.grandma_been_sold
     }
 
+    pub fn total_sold(&self) -> u32 {
+        Building::variants()
+            .map(|b| self.state.buildings.get(b).sold)
+            .sum()
+    }
+
+    pub fn record_bought(&mut self, building: Building, n: u32) {
+        let state = self.state.buildings.get_mut(building);
+        state.bought = state.bought.saturating_add(n);
+    }
+
+    pub fn record_sold(&mut self, building: Building, n: u32) {
+        let state = self.state.buildings.get_mut(building);
+        state.sold = state.sold.saturating_add(n);
+    }
+
     pub fn modify_count(
         &mut self,
         building: Building,
@@ -91,6 +108,14 @@ impl Buildings {
             self.recompute(Building::Grandma);
         }
 
+        if self.state.flags.grandma_has_elder_battalion && !building.is_grandma() {
+            self.recompute(Building::Grandma);
+        }
+
+        for partner in self.state.buildings.get(building).synergies.clone() {
+            self.recompute(partner);
+        }
+
         if building.is_grandma() {
             changeset.grandmas_count = true;
 
@@ -106,6 +131,16 @@ impl Buildings {
         }
     }
 
+    pub fn modify_level(
+        &mut self,
+        building: Building,
+        f: impl FnOnce(&mut u16),
+        changeset: &mut Changeset,
+    ) {
+        self.modify(building, |state| f(&mut state.level));
+        changeset.cps = true;
+    }
+
     pub fn modify_tiered_upgrade_count(
         &mut self,
         building: Building,
@@ -127,6 +162,12 @@ impl Buildings {
         changeset.cps = true;
     }
 
+    pub fn add_synergy(&mut self, a: Building, b: Building, changeset: &mut Changeset) {
+        self.modify(a, |state| state.synergies.push(b));
+        self.modify(b, |state| state.synergies.push(a));
+        changeset.cps = true;
+    }
+
     pub fn set_thousand_fingers_mult(&mut self, mult: Option<f64>, changeset: &mut Changeset) {
         self.state.flags.thousand_fingers_mult = mult;
         self.recompute(Building::Cursor);
@@ -163,14 +204,35 @@ impl Buildings {
         changeset.cps = true;
     }
 
+    pub fn set_grandma_has_elder_battalion(&mut self, enable: bool, changeset: &mut Changeset) {
+        self.state.flags.grandma_has_elder_battalion = enable;
+        self.recompute(Building::Grandma);
+        changeset.cps = true;
+    }
+
+    pub fn set_cost_mult(&mut self, mult: f64) {
+        if self.computed.cost_mult == mult {
+            return;
+        }
+
+        self.computed.cost_mult = mult;
+        for building in Building::variants() {
+            self.recompute(building);
+        }
+    }
+
     fn modify(&mut self, building: Building, f: impl FnOnce(&mut BuildingState)) {
         f(self.state.buildings.get_mut(building));
         self.recompute(building);
     }
 
     fn recompute(&mut self, building: Building) {
-        *self.computed.buildings.get_mut(building) =
-            BuildingComputed::new(&self.state.buildings, &self.state.flags, building);
+        *self.computed.buildings.get_mut(building) = BuildingComputed::new(
+            &self.state.buildings,
+            &self.state.flags,
+            self.computed.cost_mult,
+            building,
+        );
     }
 }
 
@@ -183,30 +245,34 @@ impl fmt::Debug for Buildings {
 macros::serialize_via_state!(Buildings => BuildingsState as |b| b.state);
 macros::deserialize_via_state!(Buildings => BuildingsState as Buildings::from_state);
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 struct BuildingsState {
     #[serde(flatten)]
     buildings: BuildingMap<BuildingState>,
     flags: BuildingsFlags,
 }
 
+#[derive(Clone)]
 struct BuildingsComputed {
     buildings: BuildingMap<BuildingComputed>,
     total_count: u16,
+    // set from the pantheon
+    cost_mult: f64,
 }
 
 impl BuildingsComputed {
     fn new(state: &BuildingsState) -> Self {
         Self {
             buildings: BuildingMap::new(|b| {
-                BuildingComputed::new(&state.buildings, &state.flags, b)
+                BuildingComputed::new(&state.buildings, &state.flags, 1.0, b)
             }),
             total_count: state.buildings.count(),
+            cost_mult: 1.0,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 struct BuildingsFlags {
     thousand_fingers_mult: Option<f64>,
     grandma_has_bingo_center: bool,
@@ -215,6 +281,8 @@ struct BuildingsFlags {
     grandma_has_communal_brainsweep: bool,
     grandma_has_elder_pact: bool,
     grandma_been_sold: bool,
+    #[serde(default)]
+    grandma_has_elder_battalion: bool,
 }
 
 #[derive(
@@ -286,6 +354,34 @@ pub enum Building {
     You,
 }
 
+#[derive(Name, Variants, Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[name(base = "title case")]
+pub enum BuildingBulk {
+    #[default]
+    #[name(base = "x1")]
+    One,
+    #[name(base = "x10")]
+    Ten,
+    #[name(base = "x100")]
+    Hundred,
+    Max,
+}
+
+impl BuildingBulk {
+    pub fn count(self) -> Option<u16> {
+        match self {
+            Self::One => Some(1),
+            Self::Ten => Some(10),
+            Self::Hundred => Some(100),
+            Self::Max => None,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        Self::VARIANTS[(self as usize + 1) % Self::VARIANT_COUNT]
+    }
+}
+
 impl Building {
     pub fn nth(index: usize) -> Option<Self> {
         Self::VARIANTS.get(index).copied()
@@ -319,14 +415,50 @@ impl BuildingInfo<'_> {
         self.state.has_grandma_job_upgrade
     }
 
+    pub fn level(&self) -> u16 {
+        self.state.level
+    }
+
+    pub fn bought(&self) -> u32 {
+        self.state.bought
+    }
+
+    pub fn sold(&self) -> u32 {
+        self.state.sold
+    }
+
     pub fn cost(&self) -> Cost {
         Cost::Cookies(self.computed.cost)
     }
 
+    pub fn level_up_cost(&self) -> Cost {
+        Cost::SugarLumps(self.state.level.saturating_add(1))
+    }
+
     pub fn sell_cost(&self) -> Cost {
         Cost::Cookies(self.computed.sell_cost)
     }
 
+    pub fn bulk_cost(&self, n: u16) -> Cost {
+        Cost::Cookies(calc::building_bulk_cost(
+            self.computed.base_cost,
+            self.state.count,
+            n,
+        ))
+    }
+
+    pub fn bulk_sell_cost(&self, n: u16) -> Cost {
+        Cost::Cookies(calc::building_bulk_sell_cost(
+            self.computed.base_cost,
+            self.state.count,
+            n,
+        ))
+    }
+
+    pub fn max_affordable(&self, cookies: f64) -> u16 {
+        calc::building_max_affordable(self.computed.base_cost, self.state.count, cookies)
+    }
+
     pub fn cps(&self) -> f64 {
         self.computed.cps
     }
@@ -341,6 +473,9 @@ impl fmt::Debug for BuildingInfo<'_> {
             .field("sell_cost", &self.computed.sell_cost)
             .field("cookies_all_time", &self.state.cookies_all_time)
             .field("tiered_upgrade_count", &self.state.tiered_upgrade_count)
+            .field("level", &self.state.level)
+            .field("bought", &self.state.bought)
+            .field("sold", &self.state.sold)
             .field(
                 "has_grandma_job_upgrade",
                 &self.state.has_grandma_job_upgrade,
@@ -349,15 +484,25 @@ impl fmt::Debug for BuildingInfo<'_> {
     }
 }
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct BuildingState {
     pub count: u16,
     pub cookies_all_time: f64,
     pub tiered_upgrade_count: u16,
     pub has_grandma_job_upgrade: bool,
+    #[serde(default)]
+    pub level: u16,
+    #[serde(default)]
+    pub bought: u32,
+    #[serde(default)]
+    pub sold: u32,
+    #[serde(default)]
+    pub synergies: Vec<Building>,
 }
 
+#[derive(Clone)]
 struct BuildingComputed {
+    base_cost: f64,
     cost: f64,
     sell_cost: f64,
     cps: f64,
@@ -367,10 +512,12 @@ impl BuildingComputed {
     fn new(
         buildings: &BuildingMap<BuildingState>,
         flags: &BuildingsFlags,
+        cost_mult: f64,
         building: Building,
     ) -> Self {
         let state = buildings.get(building);
-        let cost = calc::building_cost(building, state.count);
+        let base_cost = building.base_cost() * cost_mult;
+        let cost = calc::building_cost(base_cost, state.count);
         let sell_cost = calc::building_sell_cost(cost);
 
         let building_class = match building {
@@ -390,6 +537,9 @@ impl BuildingComputed {
                 elder_pact_portal_count: flags
                     .grandma_has_elder_pact
                     .then_some(buildings.portal.count),
+                elder_battalion_building_count: flags
+                    .grandma_has_elder_battalion
+                    .then_some(buildings.count() - buildings.grandma.count),
                 job_upgrade_count: buildings.grandma_job_upgrade_count(),
             },
             _ => calc::BuildingCpsClass::Other {
@@ -399,14 +549,26 @@ impl BuildingComputed {
             },
         };
 
+        let synergies = state
+            .synergies
+            .iter()
+            .map(|partner| calc::BuildingSynergy {
+                partner_count: buildings.get(*partner).count,
+                partner_is_higher_tier: *partner > building,
+            })
+            .collect();
+
         let cps = calc::building_cps(
             building,
             building_class,
             state.count,
             state.tiered_upgrade_count,
+            synergies,
+            state.level,
         );
 
         Self {
+            base_cost,
             cost,
             sell_cost,
             cps,
@@ -415,7 +577,7 @@ impl BuildingComputed {
 }
 
 enum_map! {
-    #[derive(Serialize, Deserialize, Default, Debug)]
+    #[derive(Serialize, Deserialize, Default, Debug, Clone)]
     struct BuildingMap of Building {
         cursor: Cursor,
         grandma: Grandma,
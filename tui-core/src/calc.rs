@@ -1,15 +1,25 @@
 use crate::{BuildingInfo, State, building::Building, cps::Cps};
 use cookie_clicker_tui_calc as calc;
 
-pub use calc::{cps::building::CpsClass as BuildingCpsClass, thousand_fingers::ThousandFingers};
+pub use calc::{
+    cps::building::{CpsClass as BuildingCpsClass, Synergy as BuildingSynergy},
+    thousand_fingers::ThousandFingers,
+};
 
 pub fn cps(state: &State) -> calc::cps::Cps {
     let base = calc::cps::base::Cps {
         building_cpses: state.buildings.infos().map(|i| i.cps()),
     };
     let addl = calc::cps::addl::Cps {
-        grandmapocalypse_mults: state.grandmapocalypse.cps_mults().iter().copied(),
-        kitten_mult: state.milk.cps_mult(),
+        prestige_mult: calc::prestige::cps_mult(state.prestige.level()),
+        heavenly_upgrades_mult: state.heavenly_upgrades.cps_mult(),
+        pantheon_mult: state.pantheon.cps_mult(),
+        modifiers_mult: state.modifiers.cps_mult(),
+        kitten_mult: dragon_boosted_mult(state.milk.cps_mult(), state.dragon.kitten_boost()),
+        buffs_mult: state.buffs.cps_mult(),
+        garden_mult: state.garden.cps_mult(),
+        santa_mult: state.santa.cps_mult(),
+        dragon_mult: state.dragon.cps_mult(),
         wrinkler_count: state.grandmapocalypse.wrinklers().len(),
         has_elder_covenant: state.grandmapocalypse.is_appeased_permanently(),
     };
@@ -21,6 +31,8 @@ pub fn building_cps(
     building_class: BuildingCpsClass,
     count: u16,
     tiered_upgrade_count: u16,
+    synergies: Vec<BuildingSynergy>,
+    level: u16,
 ) -> f64 {
     calc::cps::building::Cps {
         building_no: building as u16,
@@ -28,6 +40,8 @@ pub fn building_cps(
         building_class,
         count,
         tiered_upgrade_count,
+        synergies,
+        level,
     }
     .calc()
 }
@@ -40,31 +54,60 @@ pub fn kitten_cps_mult(milk_percentage: u16, kitten_factors: &[f64]) -> f64 {
     .calc()
 }
 
-pub fn cpc(thousand_fingers: Option<(u16, f64)>) -> f64 {
-    match thousand_fingers {
-        Some((non_cursor_buildings_count, mult)) => {
-            calc::cpc::Cpc::ThousandFingers(calc::thousand_fingers::ThousandFingers {
+pub fn cpc(thousand_fingers: Option<(u16, f64)>, buffs_mult: f64, garden_mult: f64) -> f64 {
+    calc::cpc::Cpc {
+        thousand_fingers: thousand_fingers.map(|(non_cursor_buildings_count, mult)| {
+            calc::thousand_fingers::ThousandFingers {
                 non_cursor_buildings_count,
                 mult,
-            })
-        }
-        None => calc::cpc::Cpc::Basic,
+            }
+        }),
+        buffs_mult,
+        garden_mult,
     }
     .calc()
 }
 
-pub fn building_cost(building: Building, count: u16) -> f64 {
-    calc::cost::building(building.base_cost(), count)
+pub fn building_cost(base_cost: f64, count: u16) -> f64 {
+    calc::cost::building(base_cost, count)
 }
 
 pub fn building_sell_cost(cost: f64) -> f64 {
     calc::cost::building_sell(cost)
 }
 
+pub fn synergy_mult(partner_count: u16, partner_is_higher_tier: bool) -> f64 {
+    calc::upgrade::synergy_mult(partner_count, partner_is_higher_tier)
+}
+
+pub fn building_bulk_cost(base_cost: f64, count: u16, n: u16) -> f64 {
+    calc::cost::building_bulk(base_cost, count, n)
+}
+
+pub fn building_bulk_sell_cost(base_cost: f64, count: u16, n: u16) -> f64 {
+    calc::cost::building_bulk_sell(base_cost, count, n)
+}
+
+pub fn building_max_affordable(base_cost: f64, count: u16, cookies: f64) -> u16 {
+    calc::cost::building_max_affordable(base_cost, count, cookies)
+}
+
 pub fn elder_pledge_cost(appeased_times: usize) -> f64 {
     calc::cost::elder_pledge(appeased_times)
 }
 
+pub fn season_switcher_cost(cps: f64, switches: usize) -> f64 {
+    calc::cost::season_switcher(cps, switches)
+}
+
+pub fn easter_egg_cost(eggs_owned: usize) -> f64 {
+    calc::cost::easter_egg(eggs_owned)
+}
+
+pub fn santa_level_cost(level: u8) -> f64 {
+    calc::cost::santa_level(level)
+}
+
 pub fn grandma_job_upgrade_num_req_for_1p(building: Building) -> u16 {
     calc::upgrade::grandma_job_num_req_for_1p(building as u16)
 }
@@ -73,6 +116,74 @@ pub fn wrinkler_pop_cookies(eaten: f64, shiny: bool) -> f64 {
     calc::wrinkler::pop_cookies(eaten, shiny)
 }
 
+pub fn golden_cookie_lucky_cookies(cookies: f64, cps: f64) -> f64 {
+    calc::golden_cookie::lucky_cookies(cookies, cps)
+}
+
+pub fn golden_cookie_building_special_mult(count: u16) -> f64 {
+    calc::golden_cookie::building_special_mult(count)
+}
+
+pub fn reindeer_cookies(cps: f64) -> f64 {
+    calc::golden_cookie::reindeer_cookies(cps)
+}
+
+pub fn golden_cookie_ruin_cookies(cookies: f64, cps: f64) -> f64 {
+    calc::golden_cookie::ruin_cookies(cookies, cps)
+}
+
+pub fn golden_cookie_cursed_finger_cpc(cps: f64, duration_secs: f64) -> f64 {
+    calc::golden_cookie::cursed_finger_cpc(cps, duration_secs)
+}
+
+pub fn dragon_boosted_mult(mult: f64, boost: f64) -> f64 {
+    calc::dragon::boosted_mult(mult, boost)
+}
+
+pub fn prestige_level(cookies_all_time: f64) -> f64 {
+    calc::prestige::level(cookies_all_time)
+}
+
+pub fn sugar_lump_ripening_mult(grandmas_count: u16) -> f64 {
+    calc::sugar_lump::ripening_mult(grandmas_count)
+}
+
+pub fn garden_seed_cost(cps: f64, cost_mins: f64) -> f64 {
+    calc::garden::seed_cost(cps, cost_mins)
+}
+
+pub fn garden_harvest_cookies(cookies: f64, cps: f64, cps_mins: f64, bank_ratio: f64) -> f64 {
+    calc::garden::harvest_cookies(cookies, cps, cps_mins, bank_ratio)
+}
+
+pub fn garden_effect_mult(mult: f64, soil_mult: f64) -> f64 {
+    calc::garden::effect_mult(mult, soil_mult)
+}
+
+pub fn grimoire_max_magic(towers: u16, level: u16) -> f64 {
+    calc::grimoire::max_magic(towers, level)
+}
+
+pub fn grimoire_magic_regen_per_sec(magic: f64, max_magic: f64) -> f64 {
+    calc::grimoire::magic_regen_per_sec(magic, max_magic)
+}
+
+pub fn grimoire_spell_cost(base: f64, percent: f64, max_magic: f64) -> f64 {
+    calc::grimoire::spell_cost(base, percent, max_magic)
+}
+
+pub fn grimoire_backfire_chance(base: f64, cost: f64, max_magic: f64) -> f64 {
+    calc::grimoire::backfire_chance(base, cost, max_magic)
+}
+
+pub fn grimoire_conjure_baked_goods_cookies(cookies: f64, cps: f64) -> f64 {
+    calc::grimoire::conjure_baked_goods_cookies(cookies, cps)
+}
+
+pub fn grimoire_conjure_baked_goods_backfire_cookies(cookies: f64, cps: f64) -> f64 {
+    calc::grimoire::conjure_baked_goods_backfire_cookies(cookies, cps)
+}
+
 pub fn building_display_final_cps(info: BuildingInfo, cps: &Cps) -> f64 {
     info.cps() - (info.cps() * cps.debuff_ratio)
 }
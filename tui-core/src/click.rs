@@ -1,11 +1,17 @@
-use crate::{Building, Changeset, building::Buildings, calc, macros};
+use crate::{
+    Building, Changeset, buffs::Buffs, building::Buildings, calc, dragon::Dragon, garden::Garden,
+    macros,
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Click {
     state: ClickState,
     cpc: f64,
     non_cursor_buildings_count: Option<u16>,
+    buffs_mult: f64,
+    buffs_gain: f64,
+    garden_mult: f64,
 }
 
 impl Click {
@@ -18,14 +24,33 @@ impl Click {
             state,
             cpc: 1.0,
             non_cursor_buildings_count: None,
+            buffs_mult: 1.0,
+            buffs_gain: 0.0,
+            garden_mult: 1.0,
         }
     }
 
-    pub fn tick(&mut self, buildings: &Buildings, changeset: &Changeset) {
-        if changeset.buildings_count || self.non_cursor_buildings_count.is_none() {
+    pub fn tick(
+        &mut self,
+        buildings: &Buildings,
+        buffs: &Buffs,
+        garden: &Garden,
+        dragon: &Dragon,
+        changeset: &Changeset,
+    ) {
+        let first = self.non_cursor_buildings_count.is_none();
+
+        if changeset.buildings_count || first {
             self.non_cursor_buildings_count =
                 Some(buildings.total_count() - buildings.count(Building::Cursor));
         }
+
+        if changeset.buildings_count || changeset.cps || first {
+            self.buffs_mult = calc::dragon_boosted_mult(buffs.cpc_mult(), dragon.cpc_buffs_boost());
+            self.buffs_gain = buffs.cpc_gain();
+            self.garden_mult = garden.cpc_mult();
+            self.recalc_cpc();
+        }
     }
 
     pub fn cpc(&self) -> f64 {
@@ -41,14 +66,16 @@ impl Click {
         self.cpc = calc::cpc(
             self.non_cursor_buildings_count
                 .zip(self.state.thousand_fingers_mult),
-        );
+            self.buffs_mult,
+            self.garden_mult,
+        ) + self.buffs_gain;
     }
 }
 
 macros::serialize_via_state!(Click => CursorState as |c| c.state);
 macros::deserialize_via_state!(Click => ClickState as Click::from_state);
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 struct ClickState {
     thousand_fingers_mult: Option<f64>,
 }
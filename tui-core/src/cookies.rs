@@ -3,7 +3,7 @@ use cookie_clicker_tui_utils::{frames::FPS, refresh::Refresh};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Cookies {
     current: f64,
     all_time: f64,
@@ -58,12 +58,18 @@ impl Cookies {
         self.gain_bulk.enqueue(amount);
     }
 
+    // only the profit made over what was paid counts towards all time cookies
+    pub fn gain_from_sale(&mut self, amount: f64, paid: f64) {
+        self.current += amount;
+        self.all_time += (amount - paid).max(0.0);
+    }
+
     pub fn lose(&mut self, amount: f64) {
         self.current -= amount;
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct GainBulk {
     display_queue: VecDeque<f64>,
     refresh: Refresh,
@@ -4,6 +4,8 @@ use std::cmp::Ordering;
 #[derive(Debug, Copy, Clone)]
 pub enum Cost {
     Cookies(f64),
+    HeavenlyChips(f64),
+    SugarLumps(u16),
     Dyn(CostDyn),
 }
 
@@ -11,6 +13,8 @@ impl Cost {
     pub(crate) fn resolve(self, state: &State, cps: &Cps) -> CostResolved {
         match self {
             Self::Cookies(c) => CostResolved::Cookies(c),
+            Self::HeavenlyChips(c) => CostResolved::HeavenlyChips(c),
+            Self::SugarLumps(c) => CostResolved::SugarLumps(c),
             Self::Dyn(c) => (c.f)(state, cps),
         }
     }
@@ -27,12 +31,16 @@ impl Cost {
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub enum CostResolved {
     Cookies(f64),
+    HeavenlyChips(f64),
+    SugarLumps(u16),
 }
 
 impl CostResolved {
     pub(crate) fn affordable(self, state: &State) -> bool {
         match self {
             Self::Cookies(c) => c <= state.cookies.current(),
+            Self::HeavenlyChips(c) => c <= state.prestige.heavenly_chips(),
+            Self::SugarLumps(c) => c <= state.sugar_lumps.count(),
         }
     }
 
@@ -40,11 +48,15 @@ impl CostResolved {
         #[derive(PartialEq, Eq, PartialOrd, Ord)]
         enum CostKind {
             Cookies,
+            HeavenlyChips,
+            SugarLumps,
         }
         impl From<CostResolved> for CostKind {
             fn from(cost: CostResolved) -> Self {
                 match cost {
                     CostResolved::Cookies(_) => Self::Cookies,
+                    CostResolved::HeavenlyChips(_) => Self::HeavenlyChips,
+                    CostResolved::SugarLumps(_) => Self::SugarLumps,
                 }
             }
         }
@@ -52,7 +64,11 @@ impl CostResolved {
             .cmp(&CostKind::from(b))
             .then_with(|| match (a, b) {
                 (CostResolved::Cookies(a), CostResolved::Cookies(b)) => f64::total_cmp(&a, &b),
-                #[allow(unreachable_patterns)] // for future expansion
+                (CostResolved::HeavenlyChips(a), CostResolved::HeavenlyChips(b)) => {
+                    f64::total_cmp(&a, &b)
+                }
+                (CostResolved::SugarLumps(a), CostResolved::SugarLumps(b)) => a.cmp(&b),
+                // kinds are equal by this point
                 _ => unreachable!(),
             })
     }
@@ -64,7 +80,7 @@ pub struct CostDyn {
 }
 
 impl CostDyn {
-    pub(crate) fn new(f: fn(&State, &Cps) -> CostResolved) -> Self {
+    pub(crate) const fn new(f: fn(&State, &Cps) -> CostResolved) -> Self {
         Self { f }
     }
 }
@@ -6,6 +6,8 @@ pub struct Cps {
     #[allow(unused)]
     pub base: f64,
     pub total: f64,
+    // the total without temporary buffs
+    pub unbuffed: f64,
     #[allow(unused)]
     pub wrinkled: f64,
     pub debuff_ratio: f64,
@@ -18,6 +20,7 @@ impl Cps {
         Self {
             base: calc.base,
             total: calc.total,
+            unbuffed: calc.unbuffed,
             wrinkled: calc.wrinkled,
             debuff_ratio: calc.debuff_ratio,
             refresh: Refresh::new(3.0),
@@ -0,0 +1,253 @@
+use crate::{Building, Changeset, State};
+use cookie_clicker_tui_utils::num;
+use enum_assoc::Assoc;
+use enum_fun::{Name, Variants};
+use serde::{Deserialize, Serialize};
+
+const COOKIE_LEVELS: [f64; 2] = [1.0 * num::MILLION, 2.0 * num::MILLION];
+const BUILDING_LEVEL_SACRIFICE: u16 = 100;
+const FINAL_LEVEL_SACRIFICE: u16 = 50;
+const AURA_SWITCH_SACRIFICE: u16 = 1;
+
+const MAX_LEVEL: u8 = (COOKIE_LEVELS.len() + Building::VARIANT_COUNT + 1) as u8;
+
+const BREATH_OF_MILK_BOOST: f64 = 0.05;
+const DRAGON_CURSOR_BOOST: f64 = 0.05;
+const RADIANT_APPETITE_CPS_MULT: f64 = 2.0;
+
+pub fn training_cost(state: &State) -> Option<DragonTrainingCost> {
+    let dragon = &state.dragon;
+
+    if !dragon.laid || dragon.level >= MAX_LEVEL {
+        return None;
+    }
+
+    let level = dragon.level as usize;
+
+    let cost = if let Some(cookies) = COOKIE_LEVELS.get(level) {
+        DragonTrainingCost::Cookies(*cookies)
+    } else if let Some(building) = Building::VARIANTS.get(level - COOKIE_LEVELS.len()) {
+        DragonTrainingCost::Building(*building, BUILDING_LEVEL_SACRIFICE)
+    } else {
+        DragonTrainingCost::AllBuildings(FINAL_LEVEL_SACRIFICE)
+    };
+
+    Some(cost)
+}
+
+// cookie costs are paid by the caller, buildings are sacrificed here
+pub(crate) fn train(state: &mut State, changeset: &mut Changeset) -> bool {
+    let Some(cost) = training_cost(state) else {
+        return false;
+    };
+
+    match cost {
+        DragonTrainingCost::Cookies(_) => {}
+        DragonTrainingCost::Building(building, count) => {
+            if state.buildings.count(building) < count {
+                return false;
+            }
+
+            state
+                .buildings
+                .modify_count(building, |c| *c -= count, changeset);
+        }
+        DragonTrainingCost::AllBuildings(count) => {
+            if Building::variants().any(|b| state.buildings.count(b) < count) {
+                return false;
+            }
+
+            for building in Building::variants() {
+                state
+                    .buildings
+                    .modify_count(building, |c| *c -= count, changeset);
+            }
+        }
+    }
+
+    state.dragon.level += 1;
+    apply(state, changeset);
+
+    true
+}
+
+pub(crate) fn set_aura(
+    state: &mut State,
+    slot: DragonAuraSlot,
+    aura: Option<DragonAura>,
+    changeset: &mut Changeset,
+) -> bool {
+    let dragon = &mut state.dragon;
+
+    if !dragon.slot_unlocked(slot) || dragon.aura(slot) == aura {
+        return false;
+    }
+
+    if let Some(aura) = aura {
+        if !dragon.aura_unlocked(aura) {
+            return false;
+        }
+
+        // switching auras costs one of the most expensive building owned
+        if let Some(building) = Building::VARIANTS
+            .iter()
+            .rev()
+            .copied()
+            .find(|b| state.buildings.count(*b) >= AURA_SWITCH_SACRIFICE)
+        {
+            state
+                .buildings
+                .modify_count(building, |c| *c -= AURA_SWITCH_SACRIFICE, changeset);
+        }
+    }
+
+    let dragon = &mut state.dragon;
+
+    // an aura can only be active in one slot at a time
+    if let Some(aura) = aura
+        && let Some(other) = dragon.slot_of(aura)
+    {
+        dragon.auras[other as usize] = None;
+    }
+    dragon.auras[slot as usize] = aura;
+
+    apply(state, changeset);
+
+    true
+}
+
+// auras affecting buildings live in the buildings flags,
+// so they need to be reapplied when those get reset
+pub(crate) fn apply(state: &mut State, changeset: &mut Changeset) {
+    let elder_battalion = state.dragon.has_aura(DragonAura::ElderBattalion);
+
+    state
+        .buildings
+        .set_grandma_has_elder_battalion(elder_battalion, changeset);
+
+    changeset.cps = true;
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct Dragon {
+    laid: bool,
+    level: u8,
+    auras: [Option<DragonAura>; DragonAuraSlot::VARIANT_COUNT],
+}
+
+impl Dragon {
+    pub(crate) fn new() -> Self {
+        Self {
+            laid: false,
+            level: 0,
+            auras: [None; DragonAuraSlot::VARIANT_COUNT],
+        }
+    }
+
+    pub fn laid(&self) -> bool {
+        self.laid
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn max_level(&self) -> u8 {
+        MAX_LEVEL
+    }
+
+    pub fn aura(&self, slot: DragonAuraSlot) -> Option<DragonAura> {
+        self.auras[slot as usize]
+    }
+
+    pub fn slot_of(&self, aura: DragonAura) -> Option<DragonAuraSlot> {
+        DragonAuraSlot::variants().find(|s| self.aura(*s) == Some(aura))
+    }
+
+    pub fn has_aura(&self, aura: DragonAura) -> bool {
+        self.slot_of(aura).is_some()
+    }
+
+    pub fn aura_unlocked(&self, aura: DragonAura) -> bool {
+        self.level >= aura.level()
+    }
+
+    pub fn slot_unlocked(&self, slot: DragonAuraSlot) -> bool {
+        self.level >= slot.level()
+    }
+
+    pub(crate) fn lay_egg(&mut self) {
+        self.laid = true;
+    }
+
+    pub(crate) fn kitten_boost(&self) -> f64 {
+        if self.has_aura(DragonAura::BreathOfMilk) {
+            BREATH_OF_MILK_BOOST
+        } else {
+            0.0
+        }
+    }
+
+    pub(crate) fn cpc_buffs_boost(&self) -> f64 {
+        if self.has_aura(DragonAura::DragonCursor) {
+            DRAGON_CURSOR_BOOST
+        } else {
+            0.0
+        }
+    }
+
+    pub(crate) fn cps_mult(&self) -> f64 {
+        if self.has_aura(DragonAura::RadiantAppetite) {
+            RADIANT_APPETITE_CPS_MULT
+        } else {
+            1.0
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DragonTrainingCost {
+    Cookies(f64),
+    Building(Building, u16),
+    AllBuildings(u16),
+}
+
+#[derive(Assoc, Name, Variants, Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[func(pub const fn level(self) -> u8)]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum DragonAuraSlot {
+    #[assoc(level = 1)]
+    First,
+    #[assoc(level = MAX_LEVEL)]
+    Second,
+}
+
+#[derive(
+    Assoc,
+    Name,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[func(pub const fn level(self) -> u8)]
+#[name(base = "title case")]
+pub enum DragonAura {
+    #[name(base = "Breath of Milk")]
+    #[assoc(level = 3)]
+    BreathOfMilk,
+    #[assoc(level = 4)]
+    DragonCursor,
+    #[assoc(level = 5)]
+    ElderBattalion,
+    #[assoc(level = MAX_LEVEL)]
+    RadiantAppetite,
+}
@@ -0,0 +1,398 @@
+use crate::{Building, Changeset, State, calc, cookies::Cookies, cps::Cps};
+use cookie_clicker_tui_utils::refresh::Refresh;
+use enum_assoc::Assoc;
+use enum_fun::{Name, Variants};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+pub const PLOT_MAX_WIDTH: usize = 6;
+pub const PLOT_MAX_HEIGHT: usize = 6;
+
+const MAX_AGE: u8 = 100;
+
+#[cfg(debug_assertions)]
+const TICK_SCALE: f64 = 1.0 / 30.0;
+
+#[cfg(not(debug_assertions))]
+const TICK_SCALE: f64 = 1.0;
+
+pub fn plot_size(farm_level: u16) -> (usize, usize) {
+    match farm_level {
+        0 => (0, 0),
+        1 => (2, 2),
+        2 => (3, 2),
+        3 => (3, 3),
+        4 => (4, 3),
+        5 => (4, 4),
+        6 => (5, 4),
+        7 => (5, 5),
+        8 => (6, 5),
+        _ => (PLOT_MAX_WIDTH, PLOT_MAX_HEIGHT),
+    }
+}
+
+pub fn tick(state: &mut State, changeset: &mut Changeset) {
+    let (width, height) = plot_size(state.buildings.info(Building::Farm).level());
+
+    if width == 0 {
+        return;
+    }
+
+    let garden = &mut state.garden;
+
+    if !garden.refresh.finish() {
+        return;
+    }
+
+    garden.grow(width, height);
+    garden.mutate(width, height, &mut state.rng);
+
+    changeset.cps = true;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Garden {
+    plot: [[Option<Plant>; PLOT_MAX_WIDTH]; PLOT_MAX_HEIGHT],
+    soil: Soil,
+    seeds: BTreeSet<Seed>,
+    refresh: Refresh,
+}
+
+impl Garden {
+    pub(crate) fn new() -> Self {
+        Self {
+            plot: Default::default(),
+            soil: Soil::Dirt,
+            seeds: BTreeSet::from([Seed::BakerWheat]),
+            refresh: Refresh::new(Soil::Dirt.tick_secs() * TICK_SCALE),
+        }
+    }
+
+    pub fn plant(&self, x: usize, y: usize) -> Option<Plant> {
+        self.plot.get(y)?.get(x).copied().flatten()
+    }
+
+    pub fn soil(&self) -> Soil {
+        self.soil
+    }
+
+    pub fn seeds(&self) -> &BTreeSet<Seed> {
+        &self.seeds
+    }
+
+    pub fn has_seed(&self, seed: Seed) -> bool {
+        self.seeds.contains(&seed)
+    }
+
+    pub fn secs_until_tick(&self) -> f64 {
+        self.refresh.until_finish_secs()
+    }
+
+    pub fn cps_mult(&self) -> f64 {
+        self.mature_plants()
+            .map(|p| calc::garden_effect_mult(p.seed.cps_mult(), self.soil.effect_mult()))
+            .product()
+    }
+
+    pub fn cpc_mult(&self) -> f64 {
+        self.mature_plants()
+            .map(|p| calc::garden_effect_mult(p.seed.cpc_mult(), self.soil.effect_mult()))
+            .product()
+    }
+
+    pub(crate) fn sow(&mut self, x: usize, y: usize, seed: Seed) {
+        self.plot[y][x] = Some(Plant { seed, age: 0 });
+    }
+
+    pub(crate) fn harvest(
+        &mut self,
+        x: usize,
+        y: usize,
+        cookies: &mut Cookies,
+        cps: &Cps,
+    ) -> Option<Plant> {
+        let plant = self.plot[y][x].take()?;
+
+        if plant.mature() {
+            self.seeds.insert(plant.seed);
+
+            if let Some((cps_mins, bank_ratio)) = plant.seed.harvest_cookies() {
+                cookies.gain_bulk(calc::garden_harvest_cookies(
+                    cookies.current(),
+                    cps.total,
+                    cps_mins,
+                    bank_ratio,
+                ));
+            }
+        }
+
+        Some(plant)
+    }
+
+    pub(crate) fn set_soil(&mut self, soil: Soil) {
+        self.soil = soil;
+        self.refresh = Refresh::new(soil.tick_secs() * TICK_SCALE);
+    }
+
+    // seeds are kept, but the plot is wiped clean
+    pub(crate) fn ascend(&mut self) {
+        self.plot = Default::default();
+    }
+
+    fn mature_plants(&self) -> impl Iterator<Item = &Plant> {
+        self.plot.iter().flatten().flatten().filter(|p| p.mature())
+    }
+
+    fn grow(&mut self, width: usize, height: usize) {
+        for row in self.plot.iter_mut().take(height) {
+            for tile in row.iter_mut().take(width) {
+                if let Some(plant) = tile {
+                    plant.age = plant.age.saturating_add(plant.seed.ageing());
+
+                    if plant.age >= MAX_AGE {
+                        *tile = None;
+                    }
+                }
+            }
+        }
+    }
+
+    fn mutate(&mut self, width: usize, height: usize, rng: &mut impl Rng) {
+        let mut sprouts = Vec::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                if self.plot[y][x].is_some() {
+                    continue;
+                }
+
+                let neighbors = self.mature_neighbors(x, y, width, height);
+
+                if let Some(seed) = Seed::variants().find(|seed| {
+                    seed.mutates_from(&neighbors)
+                        && rng.random::<f64>() < seed.mutation_chance() * self.soil.mutation_mult()
+                }) {
+                    sprouts.push((x, y, seed));
+                }
+            }
+        }
+
+        // sprouts don't count as neighbors until the next tick
+        for (x, y, seed) in sprouts {
+            self.sow(x, y, seed);
+        }
+    }
+
+    fn mature_neighbors(&self, x: usize, y: usize, width: usize, height: usize) -> Vec<Seed> {
+        let mut neighbors = Vec::new();
+
+        for ny in y.saturating_sub(1)..=(y + 1).min(height - 1) {
+            for nx in x.saturating_sub(1)..=(x + 1).min(width - 1) {
+                if (nx, ny) != (x, y)
+                    && let Some(plant) = self.plot[ny][nx]
+                    && plant.mature()
+                {
+                    neighbors.push(plant.seed);
+                }
+            }
+        }
+
+        neighbors
+    }
+}
+
+impl Default for Garden {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct Plant {
+    seed: Seed,
+    age: u8,
+}
+
+impl Plant {
+    pub fn seed(self) -> Seed {
+        self.seed
+    }
+
+    pub fn age(self) -> u8 {
+        self.age
+    }
+
+    pub fn mature(self) -> bool {
+        self.age >= self.seed.mature_age()
+    }
+}
+
+#[derive(
+    Assoc,
+    Name,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[func(pub const fn cost_mins(self) -> f64)]
+#[func(pub const fn ageing(self) -> u8)]
+#[func(pub const fn mature_age(self) -> u8)]
+#[func(pub const fn cps_mult(self) -> f64)]
+#[func(pub const fn cpc_mult(self) -> f64)]
+#[func(const fn mutation_chance(self) -> f64)]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum Seed {
+    #[name(base = "Baker's Wheat", lower = "baker's wheat")]
+    #[assoc(
+        cost_mins = 1.0,
+        ageing = 7,
+        mature_age = 35,
+        cps_mult = 1.01,
+        cpc_mult = 1.0,
+        mutation_chance = 0.0
+    )]
+    BakerWheat,
+    #[assoc(
+        cost_mins = 5.0,
+        ageing = 6,
+        mature_age = 20,
+        cps_mult = 1.0,
+        cpc_mult = 1.02,
+        mutation_chance = 0.05
+    )]
+    Thumbcorn,
+    #[assoc(
+        cost_mins = 15.0,
+        ageing = 3,
+        mature_age = 55,
+        cps_mult = 1.03,
+        cpc_mult = 1.0,
+        mutation_chance = 0.01
+    )]
+    Cronerice,
+    #[assoc(
+        cost_mins = 15.0,
+        ageing = 4,
+        mature_age = 25,
+        cps_mult = 1.0,
+        cpc_mult = 1.0,
+        mutation_chance = 0.02
+    )]
+    Chocoroot,
+    #[assoc(
+        cost_mins = 45.0,
+        ageing = 2,
+        mature_age = 80,
+        cps_mult = 1.01,
+        cpc_mult = 1.0,
+        mutation_chance = 0.01
+    )]
+    Bakeberry,
+    #[assoc(
+        cost_mins = 60.0,
+        ageing = 2,
+        mature_age = 60,
+        cps_mult = 1.0,
+        cpc_mult = 1.0,
+        mutation_chance = 0.001
+    )]
+    Queenbeet,
+}
+
+impl Seed {
+    // (minutes of cps, max ratio of bank) gained when harvested mature
+    pub fn harvest_cookies(self) -> Option<(f64, f64)> {
+        match self {
+            Self::Chocoroot => Some((3.0, 0.03)),
+            Self::Bakeberry => Some((30.0, 0.03)),
+            Self::Queenbeet => Some((60.0, 0.04)),
+            Self::BakerWheat | Self::Thumbcorn | Self::Cronerice => None,
+        }
+    }
+
+    fn parents(self) -> Option<(Self, Self)> {
+        match self {
+            Self::BakerWheat => None,
+            Self::Thumbcorn => Some((Self::BakerWheat, Self::BakerWheat)),
+            Self::Cronerice => Some((Self::BakerWheat, Self::Thumbcorn)),
+            Self::Chocoroot => Some((Self::BakerWheat, Self::Cronerice)),
+            Self::Bakeberry => Some((Self::BakerWheat, Self::Chocoroot)),
+            Self::Queenbeet => Some((Self::Chocoroot, Self::Bakeberry)),
+        }
+    }
+
+    fn mutates_from(self, neighbors: &[Seed]) -> bool {
+        let Some((a, b)) = self.parents() else {
+            return false;
+        };
+
+        if a == b {
+            neighbors.iter().filter(|n| **n == a).count() >= 2
+        } else {
+            neighbors.contains(&a) && neighbors.contains(&b)
+        }
+    }
+}
+
+#[derive(
+    Assoc,
+    Name,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[func(pub const fn tick_secs(self) -> f64)]
+#[func(pub const fn effect_mult(self) -> f64)]
+#[func(pub const fn mutation_mult(self) -> f64)]
+#[func(pub const fn farms_req(self) -> u16)]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum Soil {
+    #[assoc(
+        tick_secs = 300.0,
+        effect_mult = 1.0,
+        mutation_mult = 1.0,
+        farms_req = 0
+    )]
+    Dirt,
+    #[assoc(
+        tick_secs = 180.0,
+        effect_mult = 0.75,
+        mutation_mult = 1.0,
+        farms_req = 50
+    )]
+    Fertilizer,
+    #[assoc(
+        tick_secs = 900.0,
+        effect_mult = 1.25,
+        mutation_mult = 1.0,
+        farms_req = 100
+    )]
+    Clay,
+    #[name(base = "Wood Chips", lower = "wood chips")]
+    #[assoc(
+        tick_secs = 300.0,
+        effect_mult = 0.25,
+        mutation_mult = 3.0,
+        farms_req = 300
+    )]
+    WoodChips,
+}
@@ -1,49 +1,109 @@
-use crate::{macros, spawner::Spawner};
+use crate::{
+    Building, Changeset,
+    buffs::{Buff, BuffSource, Buffs},
+    building::Buildings,
+    calc,
+    cookies::Cookies,
+    cps::Cps,
+    grandmapocalypse::Grandmapocalypse,
+    spawner::Spawner,
+};
 use cookie_clicker_tui_utils::refresh::Refresh;
 use enum_assoc::Assoc;
 use enum_fun::Variants;
-use rand::seq::IndexedRandom;
+use rand::{Rng, seq::IndexedRandom};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 const DEFAULT_TMIN_SECS: f64 = 300.0;
 const DEFAULT_TMAX_SECS: f64 = 900.0;
 const DEFAULT_DURATION_SECS: f64 = 13.0;
 
-#[derive(Debug)]
+const FRENZY_MULT: f64 = 7.0;
+const FRENZY_DURATION_SECS: f64 = 77.0;
+const CLICK_FRENZY_MULT: f64 = 777.0;
+const CLICK_FRENZY_DURATION_SECS: f64 = 13.0;
+const BUILDING_SPECIAL_DURATION_SECS: f64 = 30.0;
+const BUILDING_SPECIAL_MIN_COUNT: u16 = 10;
+
+const CLOT_MULT: f64 = 0.5;
+const CLOT_DURATION_SECS: f64 = 66.0;
+const ELDER_FRENZY_MULT: f64 = 666.0;
+const ELDER_FRENZY_DURATION_SECS: f64 = 6.0;
+const CURSED_FINGER_DURATION_SECS: f64 = 10.0;
+
+// the cookies on screen are saved too, so a save picks up exactly where it left off
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GoldenCookies {
+    #[serde(flatten)]
     state: GoldenCookieState,
+    #[serde(default)]
     list: GoldenCookieList,
 }
 
 impl GoldenCookies {
     pub(crate) fn new() -> Self {
-        Self::from_state(GoldenCookieState::new())
-    }
-
-    fn from_state(state: GoldenCookieState) -> Self {
         Self {
-            state,
+            state: GoldenCookieState::new(),
             list: GoldenCookieList::new(),
         }
     }
 
-    pub(crate) fn tick(&mut self) {
+    pub(crate) fn tick(
+        &mut self,
+        grandmapocalypse: &Grandmapocalypse,
+        freq_mult: f64,
+        rng: &mut impl Rng,
+    ) {
         let missed = self.list.remove_and_count_missed();
         self.state.click_miss_count = self.state.click_miss_count.saturating_add(missed);
 
-        if self.state.spawner.spawn() {
-            self.list.spawn(self.state.cookie_duration_secs);
+        if self.state.spawner.spawn_with_freq_mult(freq_mult, rng) {
+            let kind = if rng.random::<f64>() < grandmapocalypse.wrath_cookie_share() {
+                GoldenCookieKind::Wrath
+            } else {
+                GoldenCookieKind::Golden
+            };
+
+            self.spawn(kind, rng);
         }
     }
 
-    pub(crate) fn click(&mut self, ch: char) -> bool {
-        let Some(ch) = GoldenCookieInputChar::from_char(ch) else {
-            return false;
-        };
-        let Some(cookie) = self.list.map.remove(&ch) else {
-            return false;
-        };
+    fn spawn(&mut self, kind: GoldenCookieKind, rng: &mut impl Rng) {
+        self.list.spawn(self.state.cookie_duration_secs, kind, rng);
+
+        let on_screen = self
+            .list
+            .map
+            .values()
+            .filter(|c| c.kind != GoldenCookieKind::Reindeer)
+            .count();
+        self.state.max_on_screen = self.state.max_on_screen.max(on_screen);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn click(
+        &mut self,
+        ch: char,
+        cookies: &mut Cookies,
+        buffs: &mut Buffs,
+        buildings: &Buildings,
+        cps: &Cps,
+        rng: &mut impl Rng,
+        changeset: &mut Changeset,
+    ) -> Option<GoldenCookieKind> {
+        let ch = GoldenCookieInputChar::from_char(ch)?;
+        let cookie = self.list.map.remove(&ch)?;
+
+        if cookie.kind == GoldenCookieKind::Reindeer {
+            self.state.reindeer_click_count = self.state.reindeer_click_count.saturating_add(1);
+            cookies.gain_bulk(calc::reindeer_cookies(cps.total));
+            return Some(GoldenCookieKind::Reindeer);
+        }
+
+        if cookie.kind == GoldenCookieKind::Wrath {
+            self.state.wrath_click_count = self.state.wrath_click_count.saturating_add(1);
+        }
 
         if cookie.refresh.cur_secs() <= 1.0 {
             self.state.clicked_one_at_most_1s_after_spawn = true;
@@ -53,13 +113,58 @@ impl GoldenCookies {
         }
 
         self.state.click_count = self.state.click_count.saturating_add(1);
-        true
+
+        let duration_mult = self.state.effect_duration_mult.unwrap_or(1.0);
+        let outcome = match cookie.kind {
+            GoldenCookieKind::Wrath => GoldenCookieOutcome::roll_wrath(cps, duration_mult, rng),
+            _ => GoldenCookieOutcome::roll(buildings, duration_mult, rng),
+        };
+
+        match outcome {
+            GoldenCookieOutcome::Lucky => {
+                cookies.gain_bulk(calc::golden_cookie_lucky_cookies(
+                    cookies.current(),
+                    cps.total,
+                ));
+            }
+            GoldenCookieOutcome::Ruin => {
+                cookies.lose(calc::golden_cookie_ruin_cookies(
+                    cookies.current(),
+                    cps.total,
+                ));
+            }
+            GoldenCookieOutcome::Buff(buff) => {
+                buffs.add(buff, changeset);
+            }
+        }
+
+        Some(cookie.kind)
+    }
+
+    pub(crate) fn force_spawn(&mut self, kind: GoldenCookieKind, rng: &mut impl Rng) {
+        self.spawn(kind, rng);
+    }
+
+    pub(crate) fn spawn_reindeer(&mut self, rng: &mut impl Rng) {
+        self.list.spawn(
+            self.state.cookie_duration_secs,
+            GoldenCookieKind::Reindeer,
+            rng,
+        );
     }
 
     pub(crate) fn modify_spawning(&mut self, f: impl FnOnce(&mut f64, &mut f64)) {
         self.state.spawner.modify(f);
     }
 
+    pub(crate) fn modify_duration(&mut self, f: impl FnOnce(&mut f64)) {
+        f(&mut self.state.cookie_duration_secs);
+    }
+
+    pub(crate) fn add_effect_duration_mult(&mut self, mult: f64) {
+        *self.state.effect_duration_mult.get_or_insert(1.0) *= mult;
+    }
+
     pub fn click_count(&self) -> usize {
         self.state.click_count
     }
@@ -68,6 +173,18 @@ impl GoldenCookies {
         self.state.click_miss_count
     }
 
+    pub fn reindeer_click_count(&self) -> usize {
+        self.state.reindeer_click_count
+    }
+
+    pub fn wrath_click_count(&self) -> usize {
+        self.state.wrath_click_count
+    }
+
+    pub fn max_on_screen(&self) -> usize {
+        self.state.max_on_screen
+    }
+
     pub fn clicked_one_at_most_1s_after_spawn(&self) -> bool {
         self.state.clicked_one_at_most_1s_after_spawn
     }
@@ -81,10 +198,7 @@ impl GoldenCookies {
     }
 }
 
-macros::serialize_via_state!(GoldenCookies => GoldenCookieState as |gc| gc.state);
-macros::deserialize_via_state!(GoldenCookies => GoldenCookieState as GoldenCookies::from_state);
-
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 struct GoldenCookieState {
     click_count: usize,
     click_miss_count: usize,
@@ -92,6 +206,14 @@ struct GoldenCookieState {
     clicked_one_at_most_1s_before_despawn: bool,
     cookie_duration_secs: f64,
     spawner: Spawner,
+    #[serde(default)]
+    reindeer_click_count: usize,
+    #[serde(default)]
+    wrath_click_count: usize,
+    #[serde(default)]
+    max_on_screen: usize,
+    #[serde(default)]
+    effect_duration_mult: Option<f64>,
 }
 
 impl GoldenCookieState {
@@ -103,54 +225,70 @@ impl GoldenCookieState {
             clicked_one_at_most_1s_before_despawn: false,
             cookie_duration_secs: DEFAULT_DURATION_SECS,
             spawner: Spawner::new(DEFAULT_TMIN_SECS, DEFAULT_TMAX_SECS),
+            reindeer_click_count: 0,
+            wrath_click_count: 0,
+            max_on_screen: 0,
+            effect_duration_mult: None,
         }
     }
 }
 
-#[derive(Debug)]
+// ordered so that iterating, and so clicking every cookie, is the same on every run
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(transparent)]
 struct GoldenCookieList {
-    map: HashMap<GoldenCookieInputChar, GoldenCookie>,
+    map: BTreeMap<GoldenCookieInputChar, GoldenCookie>,
 }
 
 impl GoldenCookieList {
     fn new() -> Self {
         Self {
-            map: HashMap::new(),
+            map: BTreeMap::new(),
         }
     }
 
-    fn spawn(&mut self, duration_secs: f64) {
+    fn spawn(&mut self, duration_secs: f64, kind: GoldenCookieKind, rng: &mut impl Rng) {
         let available = GoldenCookieInputChar::variants()
             .filter(|ch| !self.map.contains_key(ch))
             .collect::<Vec<_>>();
 
-        let Some(ch) = available.choose(&mut rand::rng()).copied() else {
+        let Some(ch) = available.choose(rng).copied() else {
             return;
         };
-        self.map.insert(ch, GoldenCookie::new(ch, duration_secs));
+        self.map
+            .insert(ch, GoldenCookie::new(ch, kind, duration_secs, rng));
     }
 
+    // reindeer running away don't count as missed golden cookies
     fn remove_and_count_missed(&mut self) -> usize {
         self.map
-            .extract_if(|_, cookie| cookie.refresh.finish())
+            .extract_if(.., |_, cookie| cookie.refresh.finish())
+            .filter(|(_, cookie)| cookie.kind != GoldenCookieKind::Reindeer)
             .count()
     }
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GoldenCookie {
     ch: GoldenCookieInputChar,
+    kind: GoldenCookieKind,
     x: f64,
     y: f64,
     refresh: Refresh,
 }
 
 impl GoldenCookie {
-    fn new(ch: GoldenCookieInputChar, duration_secs: f64) -> Self {
+    fn new(
+        ch: GoldenCookieInputChar,
+        kind: GoldenCookieKind,
+        duration_secs: f64,
+        rng: &mut impl Rng,
+    ) -> Self {
         Self {
             ch,
-            x: rand::random(),
-            y: rand::random(),
+            kind,
+            x: rng.random(),
+            y: rng.random(),
             refresh: Refresh::new(duration_secs),
         }
     }
@@ -159,6 +297,10 @@ impl GoldenCookie {
         self.ch.char()
     }
 
+    pub fn kind(&self) -> GoldenCookieKind {
+        self.kind
+    }
+
     pub fn x(&self) -> f64 {
         self.x
     }
@@ -168,7 +310,104 @@ impl GoldenCookie {
     }
 }
 
-#[derive(Assoc, Variants, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GoldenCookieKind {
+    Golden,
+    Wrath,
+    Reindeer,
+}
+
+enum GoldenCookieOutcome {
+    Lucky,
+    Ruin,
+    Buff(Buff),
+}
+
+impl GoldenCookieOutcome {
+    fn roll(buildings: &Buildings, duration_mult: f64, rng: &mut impl Rng) -> Self {
+        let mut choices = vec![
+            Self::Buff(Buff::cps(
+                BuffSource::Frenzy,
+                FRENZY_MULT,
+                FRENZY_DURATION_SECS * duration_mult,
+            )),
+            Self::Lucky,
+        ];
+
+        if rng.random::<f64>() < 0.1 {
+            choices.push(Self::Buff(Buff::cpc(
+                BuffSource::ClickFrenzy,
+                CLICK_FRENZY_MULT,
+                CLICK_FRENZY_DURATION_SECS * duration_mult,
+            )));
+        }
+
+        if rng.random::<f64>() < 0.25 {
+            let eligible = Building::variants()
+                .filter(|b| buildings.count(*b) >= BUILDING_SPECIAL_MIN_COUNT)
+                .collect::<Vec<_>>();
+
+            if let Some(building) = eligible.choose(rng) {
+                choices.push(Self::Buff(Buff::cps(
+                    BuffSource::BuildingSpecial(*building),
+                    calc::golden_cookie_building_special_mult(buildings.count(*building)),
+                    BUILDING_SPECIAL_DURATION_SECS * duration_mult,
+                )));
+            }
+        }
+
+        let index = rng.random_range(0..choices.len());
+        choices.swap_remove(index)
+    }
+
+    fn roll_wrath(cps: &Cps, duration_mult: f64, rng: &mut impl Rng) -> Self {
+        let mut choices = vec![
+            Self::Buff(Buff::cps(
+                BuffSource::Clot,
+                CLOT_MULT,
+                CLOT_DURATION_SECS * duration_mult,
+            )),
+            Self::Ruin,
+        ];
+
+        if rng.random::<f64>() < 0.3 {
+            choices.push(Self::Buff(Buff::cps(
+                BuffSource::ElderFrenzy,
+                ELDER_FRENZY_MULT,
+                ELDER_FRENZY_DURATION_SECS * duration_mult,
+            )));
+        }
+
+        if rng.random::<f64>() < 0.1 {
+            choices.push(Self::Buff(Buff::cps_into_cpc(
+                BuffSource::CursedFinger,
+                calc::golden_cookie_cursed_finger_cpc(
+                    cps.total,
+                    CURSED_FINGER_DURATION_SECS * duration_mult,
+                ),
+                CURSED_FINGER_DURATION_SECS * duration_mult,
+            )));
+        }
+
+        let index = rng.random_range(0..choices.len());
+        choices.swap_remove(index)
+    }
+}
+
+#[derive(
+    Assoc,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
 #[func(const fn char(self) -> char)]
 #[func(const fn from_char(ch: char) -> Option<Self>)]
 enum GoldenCookieInputChar {
@@ -191,3 +430,42 @@ enum GoldenCookieInputChar {
     #[assoc(char = '9', from_char = '9')]
     Nine,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::CoreRng;
+
+    fn positions(golden_cookies: &GoldenCookies) -> Vec<(char, f64, f64)> {
+        let mut positions = golden_cookies
+            .iter()
+            .map(|c| (c.ch(), c.x(), c.y()))
+            .collect::<Vec<_>>();
+        positions.sort_by_key(|(ch, ..)| *ch);
+        positions
+    }
+
+    #[test]
+    fn deterministic_under_seed() {
+        let grandmapocalypse = Grandmapocalypse::new();
+        let mut rngs = [42, 42, 43].map(CoreRng::with_seed);
+        let mut golden_cookies = [(); 3].map(|_| GoldenCookies::new());
+
+        for (gc, rng) in golden_cookies.iter_mut().zip(&mut rngs) {
+            gc.modify_spawning(|tmin, tmax| {
+                *tmin = 1.0;
+                *tmax = 2.0;
+            });
+
+            for _ in 0..300 {
+                gc.tick(&grandmapocalypse, 1.0, rng);
+            }
+        }
+
+        let [a, b, c] = golden_cookies.each_ref().map(positions);
+
+        assert!(!a.is_empty());
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+}
@@ -3,8 +3,9 @@ mod wrinkler;
 pub use self::wrinkler::{Wrinkler, Wrinklers};
 
 use self::Mode::*;
-use crate::{Changeset, cookies::Cookies, cps::Cps};
+use crate::{Changeset, cookies::Cookies, cps::Cps, modifiers::Modifiers};
 use cookie_clicker_tui_utils::refresh::Refresh;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 #[cfg(debug_assertions)]
@@ -13,18 +14,20 @@ const DEFAULT_APPEASED_DURATION_SECS: f64 = 10.0;
 #[cfg(not(debug_assertions))]
 const DEFAULT_APPEASED_DURATION_SECS: f64 = 30.0 * 60.0;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Grandmapocalypse {
     #[serde(flatten)]
     mode: Mode,
     appeased_temporarily_times: usize,
     appeased_permanently_ever: bool,
     appeased_duration: f64,
-    cps_mults: Vec<f64>,
+    // only read from older saves, moved into the modifiers on load
+    #[serde(default, rename = "cps_mults", skip_serializing)]
+    legacy_cps_mults: Vec<f64>,
     wrinklers: Wrinklers,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 enum Mode {
     Off,
@@ -46,7 +49,7 @@ impl Grandmapocalypse {
             appeased_temporarily_times: 0,
             appeased_permanently_ever: false,
             appeased_duration: DEFAULT_APPEASED_DURATION_SECS,
-            cps_mults: Vec::new(),
+            legacy_cps_mults: Vec::new(),
             wrinklers: Wrinklers::new(),
         }
     }
@@ -56,6 +59,8 @@ impl Grandmapocalypse {
         grandmas_count: u16,
         cps: &Cps,
         cookies: &mut Cookies,
+        modifiers: &Modifiers,
+        rng: &mut impl Rng,
         changeset: &mut Changeset,
     ) {
         match (grandmas_count, &mut self.mode) {
@@ -66,14 +71,14 @@ impl Grandmapocalypse {
                     return_to: phase, ..
                 },
             ) if changeset.grandmas_count => {
-                self.wrinklers.pop_all(cookies, changeset);
+                self.wrinklers.pop_all(cookies, modifiers, changeset);
                 self.mode = NoGrandmas { return_to: *phase };
             }
             (n, NoGrandmas { return_to: phase }) if n > 0 && changeset.grandmas_count => {
                 self.mode = Phase(*phase);
             }
             (_, Phase(phase)) => {
-                self.wrinklers.tick(*phase, cps, changeset);
+                self.wrinklers.tick(*phase, cps, rng, changeset);
             }
             (
                 _,
@@ -104,6 +109,13 @@ impl Grandmapocalypse {
         self.phase().is_some_and(|p| p == phase)
     }
 
+    // pledges and the covenant leave the grandmapocalypse phase,
+    // so they keep wrath cookies away too
+    pub fn wrath_cookie_share(&self) -> f64 {
+        self.phase()
+            .map_or(0.0, GrandmapocalypsePhase::wrath_cookie_share)
+    }
+
     pub fn is_appeased(&self) -> bool {
         matches!(self.mode, Appeased { .. })
     }
@@ -148,8 +160,8 @@ impl Grandmapocalypse {
         self.appeased_permanently_ever
     }
 
-    pub(crate) fn cps_mults(&self) -> &[f64] {
-        &self.cps_mults
+    pub(crate) fn take_legacy_cps_mults(&mut self) -> Vec<f64> {
+        std::mem::take(&mut self.legacy_cps_mults)
     }
 
     pub(crate) fn set_phase(&mut self, phase: GrandmapocalypsePhase) {
@@ -166,10 +178,15 @@ impl Grandmapocalypse {
         }
     }
 
-    pub(crate) fn appease_temporarily(&mut self, cookies: &mut Cookies, changeset: &mut Changeset) {
+    pub(crate) fn appease_temporarily(
+        &mut self,
+        cookies: &mut Cookies,
+        modifiers: &Modifiers,
+        changeset: &mut Changeset,
+    ) {
         match &mut self.mode {
             Phase(phase) => {
-                self.wrinklers.pop_all(cookies, changeset);
+                self.wrinklers.pop_all(cookies, modifiers, changeset);
                 self.mode = Appeased {
                     return_to: *phase,
                     temporary: true,
@@ -186,10 +203,15 @@ impl Grandmapocalypse {
         }
     }
 
-    pub(crate) fn appease_permanently(&mut self, cookies: &mut Cookies, changeset: &mut Changeset) {
+    pub(crate) fn appease_permanently(
+        &mut self,
+        cookies: &mut Cookies,
+        modifiers: &Modifiers,
+        changeset: &mut Changeset,
+    ) {
         match &mut self.mode {
             Phase(phase) => {
-                self.wrinklers.pop_all(cookies, changeset);
+                self.wrinklers.pop_all(cookies, modifiers, changeset);
                 self.mode = Appeased {
                     return_to: *phase,
                     temporary: false,
@@ -222,9 +244,15 @@ impl Grandmapocalypse {
         }
     }
 
-    pub(crate) fn add_cps_mult(&mut self, mult: f64, changeset: &mut Changeset) {
-        self.cps_mults.push(mult);
-        changeset.cps = true;
+    pub(crate) fn fast_forward_secs(&mut self, secs: f64) {
+        if let Appeased {
+            temporary: true,
+            refresh,
+            ..
+        } = &mut self.mode
+        {
+            refresh.fast_forward_secs(secs);
+        }
     }
 }
 
@@ -239,4 +267,8 @@ impl GrandmapocalypsePhase {
     fn wrinkler_spawn_mult(self) -> f64 {
         (self as u8 + 1) as f64
     }
+
+    fn wrath_cookie_share(self) -> f64 {
+        (self as u8 + 1) as f64 / 3.0
+    }
 }
@@ -1,6 +1,7 @@
 use super::GrandmapocalypsePhase;
-use crate::{Changeset, calc, cookies::Cookies, cps::Cps};
+use crate::{Changeset, calc, cookies::Cookies, cps::Cps, modifiers::Modifiers};
 use cookie_clicker_tui_utils::frames::FPS;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 
@@ -12,7 +13,7 @@ const DEFAULT_ODDS_PER_SPOT_PER_PHASE: f64 = 0.0001;
 #[cfg(not(debug_assertions))]
 const DEFAULT_ODDS_PER_SPOT_PER_PHASE: f64 = 0.00001;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Wrinklers {
     list: Vec<Wrinkler>,
     max_size: usize,
@@ -36,19 +37,23 @@ impl Wrinklers {
         &mut self,
         phase: GrandmapocalypsePhase,
         cps: &Cps,
+        rng: &mut impl Rng,
         changeset: &mut Changeset,
     ) {
         let available = self.available_size();
         if available > 0
-            && rand::random::<f64>()
+            && rng.random::<f64>()
                 <= self.odds_per_spot_per_phase * available as f64 * phase.wrinkler_spawn_mult()
         {
-            self.list.push(Wrinkler::new());
+            self.list.push(Wrinkler::new(rng));
             changeset.cps = true;
         }
 
+        // the withered cps is shared between every wrinkler
+        let eaten = cps.wrinkled / self.list.len().max(1) as f64;
+
         for wrinkler in &mut self.list {
-            wrinkler.eat(cps);
+            wrinkler.eat(eaten);
         }
     }
 
@@ -60,41 +65,64 @@ impl Wrinklers {
         self.popped_shiny_ever
     }
 
-    pub(crate) fn pop(&mut self, index: usize, cookies: &mut Cookies, changeset: &mut Changeset) {
-        if let Some(wrinkler) = self.get(index) {
-            let gain = calc::wrinkler_pop_cookies(wrinkler.eaten, wrinkler.shiny);
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
 
-            cookies.gain_bulk(gain);
-            changeset.cps = true;
+    pub(crate) fn add_max_size(&mut self, n: usize) {
+        self.max_size += n;
+    }
 
-            self.popped_shiny_ever |= wrinkler.shiny;
-            self.pop_count = self.pop_count.saturating_add(1);
-            self.list.remove(index);
+    pub(crate) fn pop(
+        &mut self,
+        index: usize,
+        cookies: &mut Cookies,
+        modifiers: &Modifiers,
+        changeset: &mut Changeset,
+    ) -> Option<Wrinkler> {
+        if index >= self.list.len() {
+            return None;
         }
+
+        let wrinkler = self.list.remove(index);
+
+        cookies.gain_bulk(pop_cookies(&wrinkler, modifiers));
+        changeset.cps = true;
+
+        self.popped_shiny_ever |= wrinkler.shiny;
+        self.pop_count = self.pop_count.saturating_add(1);
+
+        Some(wrinkler)
     }
 
-    pub(crate) fn pop_all(&mut self, cookies: &mut Cookies, changeset: &mut Changeset) {
-        let mut gain = 0.0;
-        let mut shiny = false;
+    pub(crate) fn pop_all(
+        &mut self,
+        cookies: &mut Cookies,
+        modifiers: &Modifiers,
+        changeset: &mut Changeset,
+    ) -> Vec<Wrinkler> {
+        let popped = std::mem::take(&mut self.list);
 
-        for wrinkler in self.list.iter() {
-            gain += calc::wrinkler_pop_cookies(wrinkler.eaten, wrinkler.shiny);
-            shiny |= wrinkler.shiny;
-        }
+        let gain = popped.iter().map(|w| pop_cookies(w, modifiers)).sum();
 
         cookies.gain_bulk(gain);
         changeset.cps = true;
 
-        self.popped_shiny_ever |= shiny;
-        self.pop_count = self.pop_count.saturating_add(1);
-        self.list.clear();
+        self.popped_shiny_ever |= popped.iter().any(|w| w.shiny);
+        self.pop_count = self.pop_count.saturating_add(popped.len());
+
+        popped
     }
 
     fn available_size(&self) -> usize {
-        self.max_size - self.list.len()
+        self.max_size.saturating_sub(self.list.len())
     }
 }
 
+fn pop_cookies(wrinkler: &Wrinkler, modifiers: &Modifiers) -> f64 {
+    calc::wrinkler_pop_cookies(wrinkler.eaten, wrinkler.shiny) * modifiers.wrinkler_pop_mult()
+}
+
 impl Deref for Wrinklers {
     type Target = [Wrinkler];
 
@@ -103,22 +131,22 @@ impl Deref for Wrinklers {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Wrinkler {
     eaten: f64,
     shiny: bool,
 }
 
 impl Wrinkler {
-    fn new() -> Self {
+    fn new(rng: &mut impl Rng) -> Self {
         Self {
             eaten: 0.0,
-            shiny: rand::random::<f64>() <= SHINY_ODDS,
+            shiny: rng.random::<f64>() <= SHINY_ODDS,
         }
     }
 
-    fn eat(&mut self, cps: &Cps) {
-        self.eaten += cps.wrinkled / FPS;
+    fn eat(&mut self, eaten: f64) {
+        self.eaten += eaten / FPS;
     }
 
     pub fn eaten(&self) -> f64 {
@@ -0,0 +1,174 @@
+use crate::{
+    Building, Changeset, GoldenCookieKind, State,
+    buffs::{Buff, BuffSource},
+    calc,
+    cps::Cps,
+};
+use cookie_clicker_tui_utils::frames::FPS;
+use enum_assoc::Assoc;
+use enum_fun::{Name, Variants};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+const BASE_BACKFIRE_CHANCE: f64 = 0.15;
+const STRETCH_TIME_MULT: f64 = 1.1;
+const STRETCH_TIME_BACKFIRE_MULT: f64 = 0.8;
+const STRETCH_TIME_MAX_EXTRA_SECS: f64 = 5.0 * 60.0;
+const HAGGLERS_CHARM_DURATION_SECS: f64 = 60.0 * 60.0;
+
+pub fn tick(state: &mut State) {
+    let max = max_magic(state);
+    let grimoire = &mut state.grimoire;
+
+    if grimoire.magic < max {
+        let regen = calc::grimoire_magic_regen_per_sec(grimoire.magic, max) / FPS;
+        grimoire.magic = f64::min(grimoire.magic + regen, max);
+    }
+}
+
+pub fn max_magic(state: &State) -> f64 {
+    let info = state.buildings.info(Building::WizardTower);
+    calc::grimoire_max_magic(info.count(), info.level())
+}
+
+pub fn spell_cost(state: &State, spell: Spell) -> f64 {
+    calc::grimoire_spell_cost(spell.cost_base(), spell.cost_percent(), max_magic(state))
+}
+
+pub fn cast(
+    state: &mut State,
+    spell: Spell,
+    cps: &Cps,
+    changeset: &mut Changeset,
+) -> Option<SpellOutcome> {
+    let cost = spell_cost(state, spell);
+
+    if cost > state.grimoire.magic {
+        return None;
+    }
+
+    let backfire_chance =
+        calc::grimoire_backfire_chance(BASE_BACKFIRE_CHANCE, cost, max_magic(state));
+
+    state.grimoire.magic -= cost;
+    state.grimoire.spells_cast = state.grimoire.spells_cast.saturating_add(1);
+
+    let outcome = if state.rng.random::<f64>() < backfire_chance {
+        SpellOutcome::Backfire
+    } else {
+        SpellOutcome::Success
+    };
+
+    match (spell, outcome) {
+        (Spell::ConjureBakedGoods, SpellOutcome::Success) => {
+            state
+                .cookies
+                .gain_bulk(calc::grimoire_conjure_baked_goods_cookies(
+                    state.cookies.current(),
+                    cps.total,
+                ));
+        }
+        (Spell::ConjureBakedGoods, SpellOutcome::Backfire) => {
+            state
+                .cookies
+                .lose(calc::grimoire_conjure_baked_goods_backfire_cookies(
+                    state.cookies.current(),
+                    cps.total,
+                ));
+        }
+        (Spell::ForceTheHandOfFate, SpellOutcome::Success) => {
+            state
+                .golden_cookies
+                .force_spawn(GoldenCookieKind::Golden, &mut state.rng);
+        }
+        (Spell::ForceTheHandOfFate, SpellOutcome::Backfire) => {
+            state
+                .golden_cookies
+                .force_spawn(GoldenCookieKind::Wrath, &mut state.rng);
+        }
+        (Spell::StretchTime, SpellOutcome::Success) => {
+            state
+                .buffs
+                .stretch(STRETCH_TIME_MULT, STRETCH_TIME_MAX_EXTRA_SECS);
+        }
+        (Spell::StretchTime, SpellOutcome::Backfire) => {
+            state
+                .buffs
+                .stretch(STRETCH_TIME_BACKFIRE_MULT, STRETCH_TIME_MAX_EXTRA_SECS);
+        }
+        (Spell::HagglersCharm, SpellOutcome::Success) => {
+            state.buffs.add(
+                Buff::effect(BuffSource::HagglersLuck, HAGGLERS_CHARM_DURATION_SECS),
+                changeset,
+            );
+        }
+        (Spell::HagglersCharm, SpellOutcome::Backfire) => {
+            state.buffs.add(
+                Buff::effect(BuffSource::HagglersMisery, HAGGLERS_CHARM_DURATION_SECS),
+                changeset,
+            );
+        }
+    }
+
+    Some(outcome)
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct Grimoire {
+    magic: f64,
+    spells_cast: usize,
+}
+
+impl Grimoire {
+    pub(crate) fn new() -> Self {
+        Self {
+            magic: 0.0,
+            spells_cast: 0,
+        }
+    }
+
+    pub fn magic(&self) -> f64 {
+        self.magic
+    }
+
+    pub fn spells_cast(&self) -> usize {
+        self.spells_cast
+    }
+}
+
+#[derive(
+    Assoc,
+    Name,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[func(pub const fn cost_base(self) -> f64)]
+#[func(pub const fn cost_percent(self) -> f64)]
+#[name(base = "title case")]
+pub enum Spell {
+    #[assoc(cost_base = 2.0, cost_percent = 0.4)]
+    ConjureBakedGoods,
+    #[name(base = "Force the Hand of Fate")]
+    #[assoc(cost_base = 10.0, cost_percent = 0.6)]
+    ForceTheHandOfFate,
+    #[assoc(cost_base = 8.0, cost_percent = 0.2)]
+    StretchTime,
+    #[name(base = "Haggler's Charm")]
+    #[assoc(cost_base = 10.0, cost_percent = 0.1)]
+    HagglersCharm,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpellOutcome {
+    Success,
+    Backfire,
+}
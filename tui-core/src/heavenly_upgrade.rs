@@ -0,0 +1,140 @@
+use crate::{Building, Cost, State, Upgrade, cps::Cps};
+use enum_assoc::Assoc;
+use enum_fun::{Name, Variants};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+const HEAVENLY_COOKIES_CPS_MULT: f64 = 1.1;
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct HeavenlyUpgrades(BTreeSet<HeavenlyUpgrade>);
+
+impl HeavenlyUpgrades {
+    pub fn new() -> Self {
+        Self(BTreeSet::new())
+    }
+
+    pub fn as_set(&self) -> &BTreeSet<HeavenlyUpgrade> {
+        &self.0
+    }
+
+    pub fn has(&self, upgrade: HeavenlyUpgrade) -> bool {
+        self.0.contains(&upgrade)
+    }
+
+    pub fn add(&mut self, upgrade: HeavenlyUpgrade) {
+        self.0.insert(upgrade);
+    }
+
+    pub fn unlocked(&self, upgrade: HeavenlyUpgrade) -> bool {
+        upgrade.parents().iter().all(|p| self.has(*p))
+    }
+
+    pub fn cps_mult(&self) -> f64 {
+        if self.has(HeavenlyUpgrade::HeavenlyCookies) {
+            HEAVENLY_COOKIES_CPS_MULT
+        } else {
+            1.0
+        }
+    }
+
+    pub fn permanent_upgrade_slots(&self) -> usize {
+        HeavenlyUpgrade::variants()
+            .filter(|u| u.is_permanent_upgrade_slot() && self.has(*u))
+            .count()
+    }
+
+    pub fn starting_buildings(&self) -> impl Iterator<Item = (Building, u16)> {
+        HeavenlyUpgrade::variants()
+            .filter(|u| self.has(*u))
+            .filter_map(|u| u.starting_buildings())
+    }
+}
+
+#[derive(
+    Assoc,
+    Name,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[func(pub const fn chips(self) -> f64)]
+#[func(pub const fn parents(self) -> &'static [HeavenlyUpgrade])]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum HeavenlyUpgrade {
+    #[assoc(chips = 1.0, parents = &[])]
+    Legacy,
+    #[assoc(chips = 3.0, parents = &[HeavenlyUpgrade::Legacy])]
+    HeavenlyCookies,
+    #[assoc(chips = 9.0, parents = &[HeavenlyUpgrade::Legacy])]
+    HowToBakeYourDragon,
+    #[assoc(chips = 1111.0, parents = &[HeavenlyUpgrade::Legacy])]
+    SeasonSwitcher,
+    #[assoc(chips = 50.0, parents = &[HeavenlyUpgrade::HeavenlyCookies])]
+    StarterKit,
+    #[assoc(chips = 5000.0, parents = &[HeavenlyUpgrade::StarterKit])]
+    StarterKitchen,
+    #[assoc(chips = 100.0, parents = &[HeavenlyUpgrade::Legacy])]
+    #[name(base = "Permanent Upgrade Slot I")]
+    PermanentUpgradeSlotI,
+    #[assoc(chips = 2000.0, parents = &[HeavenlyUpgrade::PermanentUpgradeSlotI])]
+    #[name(base = "Permanent Upgrade Slot II")]
+    PermanentUpgradeSlotII,
+    #[assoc(chips = 30000.0, parents = &[HeavenlyUpgrade::PermanentUpgradeSlotII])]
+    #[name(base = "Permanent Upgrade Slot III")]
+    PermanentUpgradeSlotIII,
+    #[assoc(chips = 400000.0, parents = &[HeavenlyUpgrade::PermanentUpgradeSlotIII])]
+    #[name(base = "Permanent Upgrade Slot IV")]
+    PermanentUpgradeSlotIV,
+    #[assoc(chips = 5000000.0, parents = &[HeavenlyUpgrade::PermanentUpgradeSlotIV])]
+    #[name(base = "Permanent Upgrade Slot V")]
+    PermanentUpgradeSlotV,
+}
+
+impl HeavenlyUpgrade {
+    pub fn cost(&self) -> Cost {
+        Cost::HeavenlyChips(self.chips())
+    }
+
+    fn starting_buildings(self) -> Option<(Building, u16)> {
+        match self {
+            Self::StarterKit => Some((Building::Cursor, 10)),
+            Self::StarterKitchen => Some((Building::Grandma, 5)),
+            _ => None,
+        }
+    }
+
+    pub fn is_permanent_upgrade_slot(&self) -> bool {
+        matches!(
+            self,
+            Self::PermanentUpgradeSlotI
+                | Self::PermanentUpgradeSlotII
+                | Self::PermanentUpgradeSlotIII
+                | Self::PermanentUpgradeSlotIV
+                | Self::PermanentUpgradeSlotV
+        )
+    }
+}
+
+pub(crate) fn permanent_upgrades(state: &State, cps: &Cps) -> Vec<Upgrade> {
+    let mut v = state
+        .owned_upgrades
+        .as_set()
+        .iter()
+        .copied()
+        .filter(|u| u.can_be_permanent())
+        .collect::<Vec<_>>();
+
+    v.sort_by(|a, b| Cost::total_cmp(b.cost(), a.cost(), state, cps));
+    v.truncate(state.heavenly_upgrades.permanent_upgrade_slots());
+    v
+}
@@ -1,4 +1,7 @@
 mod achievement;
+mod action;
+mod advisor;
+mod buffs;
 mod building;
 mod calc;
 mod changeset;
@@ -6,43 +9,75 @@ mod click;
 mod cookies;
 mod cost;
 mod cps;
+mod dragon;
+mod garden;
 mod golden_cookie;
 mod grandmapocalypse;
+mod grimoire;
+mod heavenly_upgrade;
 mod macros;
 mod milk;
+mod modifiers;
 mod news;
+mod offline;
+mod pantheon;
+mod prestige;
 mod req;
 mod research;
+mod rng;
+mod santa;
+mod season;
 mod spawner;
+mod stock_market;
 mod sugar_lumps;
 mod thousand_fingers;
 mod upgrade;
 
 pub use self::{
     achievement::{Achievement, AchievementReq},
-    building::{Building, BuildingInfo},
+    action::Action,
+    advisor::{Purchase, PurchaseAdvice},
+    buffs::{Buff, BuffSource, Buffs},
+    building::{Building, BuildingBulk, BuildingInfo},
     changeset::Changeset,
     cost::{Cost, CostDyn, CostResolved},
-    golden_cookie::{GoldenCookie, GoldenCookies},
+    dragon::{Dragon, DragonAura, DragonAuraSlot, DragonTrainingCost},
+    garden::{Garden, Plant, Seed, Soil},
+    golden_cookie::{GoldenCookie, GoldenCookieKind, GoldenCookies},
     grandmapocalypse::{Grandmapocalypse, GrandmapocalypsePhase, Wrinkler, Wrinklers},
+    grimoire::{Grimoire, Spell, SpellOutcome},
+    heavenly_upgrade::HeavenlyUpgrade,
     milk::{Milk, MilkFlavor},
     news::NewsEntry,
+    offline::OfflineProgress,
+    pantheon::{Pantheon, Spirit, SpiritSlot},
+    prestige::Prestige,
     research::Research,
-    sugar_lumps::SugarLumps,
+    santa::{Santa, SantaLevel},
+    season::{Season, Seasons},
+    stock_market::{Good, GoodState, MarketMode, StockMarket},
+    sugar_lumps::{SugarLumpKind, SugarLumpStage, SugarLumps},
     upgrade::{
         Upgrade, UpgradeEffectInfo, UpgradeInfoEffectResearch, UpgradeInfoEffectResearchWarning,
+        UpgradeInfoEffectSantaGift,
     },
 };
 
 use self::{
     achievement::Achievements,
+    advisor::PurchaseAdvisor,
     building::Buildings,
     click::Click,
     cookies::Cookies,
     cps::Cps,
+    heavenly_upgrade::HeavenlyUpgrades,
+    modifiers::{Modifier, Modifiers},
+    rng::CoreRng,
+    season::DropSource,
     thousand_fingers::ThousandFingers,
     upgrade::{AvailableUpgrades, OwnedUpgrades},
 };
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeSet, fmt};
 
@@ -58,9 +93,17 @@ impl Core {
         Self::from_state(State::new())
     }
 
-    fn from_state(state: State) -> Self {
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_state(State::with_rng(CoreRng::with_seed(seed)))
+    }
+
+    fn from_state(mut state: State) -> Self {
+        let mut changeset = Changeset::default();
+
+        state.migrate();
+        pantheon::apply(&mut state, &mut changeset);
+
         let computed = Computed::new(&state);
-        let changeset = Changeset::default();
         let everything_free = false;
 
         Self {
@@ -123,6 +166,14 @@ impl Core {
         &self.computed.available_upgrades
     }
 
+    pub fn purchase_advice(&self) -> &[PurchaseAdvice] {
+        &self.computed.advisor
+    }
+
+    pub fn best_buy(&self) -> Option<Purchase> {
+        self.computed.advisor.best()
+    }
+
     pub fn owned_achievements(&self) -> &BTreeSet<Achievement> {
         self.state.achievements.owned()
     }
@@ -143,6 +194,110 @@ impl Core {
         &self.state.golden_cookies
     }
 
+    pub fn buffs(&self) -> &Buffs {
+        &self.state.buffs
+    }
+
+    pub fn garden(&self) -> &Garden {
+        &self.state.garden
+    }
+
+    pub fn garden_plot_size(&self) -> (usize, usize) {
+        garden::plot_size(self.building_info(Building::Farm).level())
+    }
+
+    pub fn garden_seed_cost(&self, seed: Seed) -> CostResolved {
+        CostResolved::Cookies(calc::garden_seed_cost(
+            self.computed.cps.total,
+            seed.cost_mins(),
+        ))
+    }
+
+    pub fn garden_soil_unlocked(&self, soil: Soil) -> bool {
+        self.building_info(Building::Farm).count() >= soil.farms_req()
+    }
+
+    pub fn stock_market(&self) -> &StockMarket {
+        &self.state.stock_market
+    }
+
+    pub fn stock_market_unlocked(&self) -> bool {
+        self.building_info(Building::Bank).level() >= 1
+    }
+
+    pub fn stock_warehouse_limit(&self, good: Good) -> u16 {
+        self.building_info(good.building()).count()
+            + 10 * self.building_info(Building::Bank).level()
+    }
+
+    // each dollar is worth one second of cps, ignoring buffs so they can't be traded on
+    pub fn stock_price_cookies(&self, good: Good) -> f64 {
+        self.state.stock_market.good(good).price() * self.computed.cps.unbuffed
+    }
+
+    pub fn pantheon(&self) -> &Pantheon {
+        &self.state.pantheon
+    }
+
+    pub fn pantheon_unlocked(&self) -> bool {
+        self.building_info(Building::Temple).level() >= 1
+    }
+
+    pub fn grimoire(&self) -> &Grimoire {
+        &self.state.grimoire
+    }
+
+    pub fn grimoire_unlocked(&self) -> bool {
+        self.building_info(Building::WizardTower).level() >= 1
+    }
+
+    pub fn grimoire_max_magic(&self) -> f64 {
+        grimoire::max_magic(&self.state)
+    }
+
+    pub fn spell_cost(&self, spell: Spell) -> f64 {
+        grimoire::spell_cost(&self.state, spell)
+    }
+
+    pub fn seasons(&self) -> &Seasons {
+        &self.state.seasons
+    }
+
+    pub fn santa(&self) -> &Santa {
+        &self.state.santa
+    }
+
+    pub fn santa_level_cost(&self) -> Option<f64> {
+        santa::level_cost(&self.state)
+    }
+
+    pub fn dragon(&self) -> &Dragon {
+        &self.state.dragon
+    }
+
+    pub fn dragon_training_cost(&self) -> Option<DragonTrainingCost> {
+        dragon::training_cost(&self.state)
+    }
+
+    pub fn prestige(&self) -> &Prestige {
+        &self.state.prestige
+    }
+
+    pub fn prestige_level_gain(&self) -> f64 {
+        self.state
+            .prestige
+            .level_after_ascension(&self.state.cookies)
+            - self.state.prestige.level()
+    }
+
+    pub fn owned_heavenly_upgrades(&self) -> &BTreeSet<HeavenlyUpgrade> {
+        self.state.heavenly_upgrades.as_set()
+    }
+
+    pub fn heavenly_upgrade_unlocked(&self, upgrade: HeavenlyUpgrade) -> bool {
+        self.state.heavenly_upgrades.unlocked(upgrade)
+    }
+
     pub fn random_news_entry(&self) -> Option<NewsEntry> {
         self::news::get_entry(&self.state)
     }
@@ -159,6 +314,10 @@ impl Core {
         cost.resolve(&self.state, &self.computed.cps)
     }
 
+    pub fn upgrade_cost(&self, upgrade: Upgrade) -> CostResolved {
+        upgrade.resolved_cost(&self.state, &self.computed.cps)
+    }
+
     pub fn click_cookie(&mut self) {
         self.state
             .cookies
@@ -166,7 +325,28 @@ impl Core {
     }
 
     pub fn click_golden_cookie(&mut self, ch: char) -> bool {
-        self.state.golden_cookies.click(ch)
+        let Some(kind) = self.state.golden_cookies.click(
+            ch,
+            &mut self.state.cookies,
+            &mut self.state.buffs,
+            &self.state.buildings,
+            &self.computed.cps,
+            &mut self.state.rng,
+            &mut self.changeset,
+        ) else {
+            return false;
+        };
+
+        let source = match kind {
+            GoldenCookieKind::Golden | GoldenCookieKind::Wrath => {
+                pantheon::golden_cookie_clicked(&mut self.state, &mut self.changeset);
+                DropSource::GoldenCookie
+            }
+            GoldenCookieKind::Reindeer => DropSource::Reindeer,
+        };
+        season::roll_drop(&mut self.state, source, &mut self.changeset);
+
+        true
     }
 
     pub fn give_building(&mut self, building: Building) {
@@ -182,43 +362,80 @@ impl Core {
     }
 
     pub fn buy_building(&mut self, building: Building) -> bool {
-        let cost = self.resolve_cost(self.building_info(building).cost());
+        self.buy_building_n(building, 1)
+    }
 
-        if !self.affordable_resolved(cost) {
+    // buys all `n` or none of them
+    pub fn buy_building_n(&mut self, building: Building, n: u16) -> bool {
+        if n == 0 {
             return false;
         }
 
-        if !self.everything_free {
-            match cost {
-                CostResolved::Cookies(cookies) => {
-                    self.state.cookies.lose(cookies);
-                }
-            }
+        let cost = self.resolve_cost(self.building_info(building).bulk_cost(n));
+
+        if !self.affordable_resolved(cost) {
+            return false;
         }
 
-        self.give_building(building);
+        self.pay(cost);
+
+        self.state.buildings.modify_count(
+            building,
+            |c| *c = c.saturating_add(n),
+            &mut self.changeset,
+        );
+        self.state.buildings.record_bought(building, n as u32);
         true
     }
 
     pub fn sell_building(&mut self, building: Building) -> bool {
+        self.sell_building_n(building, 1)
+    }
+
+    // sells up to `n`, or every owned one if there are fewer
+    pub fn sell_building_n(&mut self, building: Building, n: u16) -> bool {
         let info = self.building_info(building);
+        let n = n.min(info.count());
 
-        if info.count() == 0 {
+        if n == 0 {
             return false;
         };
 
         if !self.everything_free {
-            match self.resolve_cost(info.sell_cost()) {
+            match self.resolve_cost(info.bulk_sell_cost(n)) {
                 CostResolved::Cookies(cookies) => {
                     self.state.cookies.gain(cookies);
                 }
+                CostResolved::HeavenlyChips(_) | CostResolved::SugarLumps(_) => {
+                    unreachable!("buildings only sell for cookies")
+                }
             }
         }
 
-        self.take_building(building);
+        self.state
+            .buildings
+            .modify_count(building, |c| *c -= n, &mut self.changeset);
+        self.state.buildings.record_sold(building, n as u32);
         true
     }
 
+    pub fn buildings_sold(&self) -> u32 {
+        self.state.buildings.total_sold()
+    }
+
+    // how many buildings a bulk mode moves right now, "max" buys as many as
+    // affordable (at least one, so it can still fail) and sells every owned one
+    pub fn building_bulk_count(&self, building: Building, bulk: BuildingBulk, sell: bool) -> u16 {
+        let info = self.building_info(building);
+
+        match (bulk.count(), sell) {
+            (Some(n), false) => n,
+            (Some(n), true) => n.min(info.count()),
+            (None, false) => info.max_affordable(self.state.cookies.current()).max(1),
+            (None, true) => info.count(),
+        }
+    }
+
     pub fn buy_upgrade(&mut self, upgrade: Upgrade) -> bool {
         if !self.computed.available_upgrades.contains(&upgrade) {
             return false;
@@ -228,19 +445,13 @@ impl Core {
             return false;
         }
 
-        let cost = self.resolve_cost(upgrade.cost());
+        let cost = self.upgrade_cost(upgrade);
 
         if !self.affordable_resolved(cost) {
             return false;
         }
 
-        if !self.everything_free {
-            match cost {
-                CostResolved::Cookies(cookies) => {
-                    self.state.cookies.lose(cookies);
-                }
-            }
-        }
+        self.pay(cost);
 
         if upgrade.should_add_to_owned() {
             self.state.owned_upgrades.add(upgrade);
@@ -251,19 +462,327 @@ impl Core {
         true
     }
 
+    pub fn harvest_sugar_lump(&mut self) -> Option<u16> {
+        sugar_lumps::harvest(&mut self.state)
+    }
+
+    pub fn level_up_building(&mut self, building: Building) -> bool {
+        if !self.state.sugar_lumps.unlocked() {
+            return false;
+        }
+
+        let cost = self.resolve_cost(self.building_info(building).level_up_cost());
+
+        if !self.affordable_resolved(cost) {
+            return false;
+        }
+
+        self.pay(cost);
+        self.state
+            .buildings
+            .modify_level(building, |l| *l += 1, &mut self.changeset);
+
+        true
+    }
+
+    pub fn plant_garden_seed(&mut self, x: usize, y: usize, seed: Seed) -> bool {
+        let (width, height) = self.garden_plot_size();
+
+        if x >= width || y >= height {
+            return false;
+        }
+
+        if self.state.garden.plant(x, y).is_some() || !self.state.garden.has_seed(seed) {
+            return false;
+        }
+
+        let cost = self.garden_seed_cost(seed);
+
+        if !self.affordable_resolved(cost) {
+            return false;
+        }
+
+        self.pay(cost);
+        self.state.garden.sow(x, y, seed);
+
+        true
+    }
+
+    pub fn harvest_garden_plant(&mut self, x: usize, y: usize) -> Option<Plant> {
+        let (width, height) = self.garden_plot_size();
+
+        if x >= width || y >= height {
+            return None;
+        }
+
+        let plant = self
+            .state
+            .garden
+            .harvest(x, y, &mut self.state.cookies, &self.computed.cps)?;
+        self.changeset.cps = true;
+
+        Some(plant)
+    }
+
+    pub fn set_garden_soil(&mut self, soil: Soil) -> bool {
+        if !self.garden_soil_unlocked(soil) || self.state.garden.soil() == soil {
+            return false;
+        }
+
+        self.state.garden.set_soil(soil);
+        self.changeset.cps = true;
+
+        true
+    }
+
+    pub fn buy_stock(&mut self, good: Good, count: u16) -> bool {
+        if !self.stock_market_unlocked() || count == 0 {
+            return false;
+        }
+
+        let Some(owned) = self
+            .state
+            .stock_market
+            .good(good)
+            .owned()
+            .checked_add(count)
+        else {
+            return false;
+        };
+
+        if owned > self.stock_warehouse_limit(good) {
+            return false;
+        }
+
+        let cookies = self.stock_price_cookies(good) * count as f64;
+        let cost = CostResolved::Cookies(cookies);
+
+        if !self.affordable_resolved(cost) {
+            return false;
+        }
+
+        self.pay(cost);
+        self.state.stock_market.buy(good, count, cookies);
+
+        true
+    }
+
+    pub fn sell_stock(&mut self, good: Good, count: u16) -> bool {
+        if !self.stock_market_unlocked() || count == 0 {
+            return false;
+        }
+
+        if self.state.stock_market.good(good).owned() < count {
+            return false;
+        }
+
+        let cookies = self.stock_price_cookies(good) * count as f64;
+        let paid = self.state.stock_market.sell(good, count);
+
+        self.state.cookies.gain_from_sale(cookies, paid);
+
+        true
+    }
+
+    pub fn slot_spirit(&mut self, slot: SpiritSlot, spirit: Option<Spirit>) -> bool {
+        if !self.pantheon_unlocked() {
+            return false;
+        }
+
+        pantheon::slot(&mut self.state, slot, spirit, &mut self.changeset)
+    }
+
+    pub fn cast_spell(&mut self, spell: Spell) -> Option<SpellOutcome> {
+        if !self.grimoire_unlocked() {
+            return None;
+        }
+
+        grimoire::cast(
+            &mut self.state,
+            spell,
+            &self.computed.cps,
+            &mut self.changeset,
+        )
+    }
+
+    pub fn level_up_santa(&mut self) -> bool {
+        let Some(cost) = self.santa_level_cost() else {
+            return false;
+        };
+
+        let cost = CostResolved::Cookies(cost);
+
+        if !self.affordable_resolved(cost) {
+            return false;
+        }
+
+        self.pay(cost);
+
+        santa::level_up(&mut self.state, &mut self.changeset)
+    }
+
+    pub fn train_dragon(&mut self) -> bool {
+        let Some(cost) = self.dragon_training_cost() else {
+            return false;
+        };
+
+        if let DragonTrainingCost::Cookies(cookies) = cost {
+            let cost = CostResolved::Cookies(cookies);
+
+            if !self.affordable_resolved(cost) {
+                return false;
+            }
+
+            self.pay(cost);
+        }
+
+        dragon::train(&mut self.state, &mut self.changeset)
+    }
+
+    pub fn set_dragon_aura(&mut self, slot: DragonAuraSlot, aura: Option<DragonAura>) -> bool {
+        dragon::set_aura(&mut self.state, slot, aura, &mut self.changeset)
+    }
+
+    pub fn buy_heavenly_upgrade(&mut self, upgrade: HeavenlyUpgrade) -> bool {
+        if self.state.heavenly_upgrades.has(upgrade) {
+            return false;
+        }
+
+        if !self.state.heavenly_upgrades.unlocked(upgrade) {
+            return false;
+        }
+
+        let cost = self.resolve_cost(upgrade.cost());
+
+        if !self.affordable_resolved(cost) {
+            return false;
+        }
+
+        self.pay(cost);
+        self.state.heavenly_upgrades.add(upgrade);
+        self.changeset.cps = true;
+
+        true
+    }
+
+    fn pay(&mut self, cost: CostResolved) {
+        if self.everything_free {
+            return;
+        }
+
+        match cost {
+            CostResolved::Cookies(cookies) => {
+                self.state.cookies.lose(cookies);
+            }
+            CostResolved::HeavenlyChips(chips) => {
+                self.state.prestige.spend_heavenly_chips(chips);
+            }
+            CostResolved::SugarLumps(lumps) => {
+                self.state.sugar_lumps.spend(lumps);
+            }
+        }
+    }
+
     pub fn pop_wrinkler(&mut self, index: usize) {
-        self.state.grandmapocalypse.wrinklers_mut().pop(
+        let popped = self.state.grandmapocalypse.wrinklers_mut().pop(
             index,
             &mut self.state.cookies,
+            &self.state.modifiers,
             &mut self.changeset,
         );
+
+        if let Some(wrinkler) = popped {
+            season::roll_drop(
+                &mut self.state,
+                DropSource::Wrinkler(wrinkler.shiny()),
+                &mut self.changeset,
+            );
+        }
     }
 
     pub fn pop_all_wrinklers(&mut self) {
-        self.state
-            .grandmapocalypse
-            .wrinklers_mut()
-            .pop_all(&mut self.state.cookies, &mut self.changeset);
+        let popped = self.state.grandmapocalypse.wrinklers_mut().pop_all(
+            &mut self.state.cookies,
+            &self.state.modifiers,
+            &mut self.changeset,
+        );
+
+        for wrinkler in popped {
+            season::roll_drop(
+                &mut self.state,
+                DropSource::Wrinkler(wrinkler.shiny()),
+                &mut self.changeset,
+            );
+        }
+    }
+
+    pub fn apply_action(&mut self, action: Action) -> bool {
+        action.apply(self)
+    }
+
+    pub fn ascend(&mut self) -> bool {
+        if self.prestige_level_gain() < 1.0 {
+            return false;
+        }
+
+        let permanent_upgrades =
+            heavenly_upgrade::permanent_upgrades(&self.state, &self.computed.cps);
+        // the rng carries on so that ascending doesn't break replays
+        let rng = std::mem::take(&mut self.state.rng);
+        let prev = std::mem::replace(&mut self.state, State::with_rng(rng));
+
+        self.state.prestige = prev.prestige;
+        self.state.prestige.ascend(&prev.cookies);
+        self.state.heavenly_upgrades = prev.heavenly_upgrades;
+        self.state.achievements = prev.achievements;
+        self.state.sugar_lumps = prev.sugar_lumps;
+        self.state.garden = prev.garden;
+        self.state.garden.ascend();
+        self.state.seasons = prev.seasons;
+        self.state.seasons.ascend();
+        self.state.santa = prev.santa;
+        self.state.santa.ascend();
+        self.state.dragon = prev.dragon;
+        dragon::apply(&mut self.state, &mut self.changeset);
+
+        for info in prev.buildings.infos() {
+            self.state.buildings.modify_level(
+                info.building(),
+                |l| *l = info.level(),
+                &mut self.changeset,
+            );
+            self.state
+                .buildings
+                .record_bought(info.building(), info.bought());
+            self.state
+                .buildings
+                .record_sold(info.building(), info.sold());
+        }
+
+        for (building, count) in self.state.heavenly_upgrades.starting_buildings() {
+            self.state
+                .buildings
+                .modify_count(building, |c| *c += count, &mut self.changeset);
+        }
+
+        for upgrade in permanent_upgrades {
+            self.state.owned_upgrades.add(upgrade);
+            upgrade.buy(&mut self.state, &mut self.changeset);
+        }
+
+        self.computed = Computed::new(&self.state);
+        self.changeset.cps = true;
+        self.changeset.buildings_count = true;
+        self.changeset.grandmas_count = true;
+        self.changeset.available_upgrades = true;
+
+        true
+    }
+
+    pub fn fast_forward_offline(&mut self, secs: f64, efficiency: f64) -> OfflineProgress {
+        let progress = offline::fast_forward(&mut self.state, &self.computed.cps, secs, efficiency);
+        self.changeset.cps = true;
+        progress
     }
 
     pub fn cheat_make_everything_free(&mut self) {
@@ -274,6 +793,15 @@ impl Core {
         self.state.grandmapocalypse.set_phase(phase);
     }
 
+    // flags the save for automation, which also stops click related achievements
+    pub fn mark_cheated(&mut self) {
+        self.state.achievements.mark_cheated();
+    }
+
+    pub fn cheated(&self) -> bool {
+        self.state.achievements.cheated()
+    }
+
     pub fn cheat_spawn_golden_cookies_fast(&mut self) {
         self.state.golden_cookies.modify_spawning(|min, max| {
             *min = 5.0;
@@ -331,7 +859,7 @@ impl Default for Core {
 macros::serialize_via_state!(Core => State as |c| c.state);
 macros::deserialize_via_state!(Core => State as Core::from_state);
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct State {
     cookies: Cookies,
     buildings: Buildings,
@@ -342,12 +870,40 @@ struct State {
     thousand_fingers: ThousandFingers,
     sugar_lumps: SugarLumps,
     research: Research,
+    #[serde(default)]
+    modifiers: Modifiers,
     grandmapocalypse: Grandmapocalypse,
     golden_cookies: GoldenCookies,
+    #[serde(default)]
+    buffs: Buffs,
+    #[serde(default)]
+    prestige: Prestige,
+    #[serde(default)]
+    heavenly_upgrades: HeavenlyUpgrades,
+    #[serde(default)]
+    garden: Garden,
+    #[serde(default)]
+    stock_market: StockMarket,
+    #[serde(default)]
+    pantheon: Pantheon,
+    #[serde(default)]
+    grimoire: Grimoire,
+    #[serde(default)]
+    seasons: Seasons,
+    #[serde(default)]
+    santa: Santa,
+    #[serde(default)]
+    dragon: Dragon,
+    #[serde(default)]
+    rng: CoreRng,
 }
 
 impl State {
     fn new() -> Self {
+        Self::with_rng(CoreRng::new())
+    }
+
+    fn with_rng(mut rng: CoreRng) -> Self {
         Self {
             cookies: Cookies::new(),
             buildings: Buildings::new(),
@@ -358,15 +914,42 @@ impl State {
             thousand_fingers: ThousandFingers::new(),
             sugar_lumps: SugarLumps::new(),
             research: Research::new(),
+            modifiers: Modifiers::new(),
             grandmapocalypse: Grandmapocalypse::new(),
             golden_cookies: GoldenCookies::new(),
+            buffs: Buffs::new(),
+            prestige: Prestige::new(),
+            heavenly_upgrades: HeavenlyUpgrades::new(),
+            garden: Garden::new(),
+            stock_market: StockMarket::with_seed(rng.random()),
+            pantheon: Pantheon::new(),
+            grimoire: Grimoire::new(),
+            seasons: Seasons::new(),
+            santa: Santa::new(),
+            dragon: Dragon::new(),
+            rng,
+        }
+    }
+
+    // older saves kept the research mults on the grandmapocalypse
+    fn migrate(&mut self) {
+        for mult in self.grandmapocalypse.take_legacy_cps_mults() {
+            self.modifiers
+                .add(Modifier::CpsMult(mult), &mut Changeset::default());
         }
     }
 
     fn tick(&mut self, computed: &Computed, changeset: &mut Changeset) {
         self.cookies.tick(&computed.cps);
         self.buildings.tick();
-        self.click.tick(&self.buildings, changeset);
+        self.buffs.tick(changeset);
+        self.click.tick(
+            &self.buildings,
+            &self.buffs,
+            &self.garden,
+            &self.dragon,
+            changeset,
+        );
         self.milk
             .tick(self.achievements.owned().len() as _, changeset);
         self.research.tick(changeset);
@@ -374,33 +957,50 @@ impl State {
             self.buildings.count(Building::Grandma),
             &computed.cps,
             &mut self.cookies,
+            &self.modifiers,
+            &mut self.rng,
             changeset,
         );
-        self.golden_cookies.tick();
+        self.golden_cookies.tick(
+            &self.grandmapocalypse,
+            self.pantheon.golden_cookie_freq_mult(),
+            &mut self.rng,
+        );
+        self.stock_market
+            .tick(self.buildings.info(Building::Bank).level());
+        self.pantheon.tick();
 
         achievement::tick(self, computed);
         sugar_lumps::tick(self, changeset);
+        garden::tick(self, changeset);
+        grimoire::tick(self);
+        season::tick(self, changeset);
     }
 }
 
 struct Computed {
     cps: Cps,
     available_upgrades: AvailableUpgrades,
+    advisor: PurchaseAdvisor,
 }
 
 impl Computed {
     fn new(state: &State) -> Self {
         let cps = Cps::new(state);
         let available_upgrades = AvailableUpgrades::new(state, &cps);
+        let advisor = PurchaseAdvisor::new(state, &cps, &available_upgrades);
 
         Self {
             cps,
             available_upgrades,
+            advisor,
         }
     }
 
     fn tick(&mut self, state: &State, changeset: &Changeset) {
         self.cps.tick(state, changeset);
         self.available_upgrades.tick(state, &self.cps, changeset);
+        self.advisor
+            .tick(state, &self.cps, &self.available_upgrades, changeset);
     }
 }
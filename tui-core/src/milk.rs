@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 const PERCENT_PER_ACHIEVEMENT: u16 = 4;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Milk {
     state: MilkState,
     computed: MilkComputed,
@@ -69,13 +69,13 @@ impl Milk {
 macros::serialize_via_state!(Milk => MilkState as |m| m.state);
 macros::deserialize_via_state!(Milk => MilkState as Milk::from_state);
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 struct MilkState {
     achievements: u16,
     kitten_factors: Vec<f64>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct MilkComputed {
     percent: u16,
     ratio: f64,
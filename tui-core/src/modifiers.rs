@@ -0,0 +1,49 @@
+use crate::Changeset;
+use serde::{Deserialize, Serialize};
+
+// permanent multipliers granted by upgrades, kept in one place rather than on each module
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(transparent)]
+pub struct Modifiers {
+    list: Vec<Modifier>,
+}
+
+impl Modifiers {
+    pub(crate) fn new() -> Self {
+        Self { list: Vec::new() }
+    }
+
+    pub(crate) fn add(&mut self, modifier: Modifier, changeset: &mut Changeset) {
+        self.list.push(modifier);
+
+        if let Modifier::CpsMult(_) = modifier {
+            changeset.cps = true;
+        }
+    }
+
+    pub(crate) fn cps_mult(&self) -> f64 {
+        self.list
+            .iter()
+            .map(|m| match m {
+                Modifier::CpsMult(mult) => *mult,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    pub(crate) fn wrinkler_pop_mult(&self) -> f64 {
+        self.list
+            .iter()
+            .map(|m| match m {
+                Modifier::WrinklerPopMult(mult) => *mult,
+                _ => 1.0,
+            })
+            .product()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub enum Modifier {
+    CpsMult(f64),
+    WrinklerPopMult(f64),
+}
@@ -3,6 +3,7 @@ use crate::{
     building::Building,
     grandmapocalypse::GrandmapocalypsePhase,
     req::{Cmp, Req},
+    season::Season,
 };
 use cookie_clicker_tui_utils::num;
 use enum_assoc::Assoc;
@@ -124,8 +125,22 @@ pub enum NewsEntry {
     Grandmapocalypse_Angered,
     #[assoc(req = Req::GrandmapocalypseAppeased())]
     Grandmapocalypse_Appeased,
+    /* -------------------------------------------------------------------------- */
+    /*                                Season Based                                */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(req = Req::Season(Season::BusinessDay))]
+    Season_BusinessDay,
+    #[assoc(req = Req::Season(Season::Christmas))]
+    Season_Christmas,
+    #[assoc(req = Req::Season(Season::Easter))]
+    Season_Easter,
+    #[assoc(req = Req::Season(Season::Halloween))]
+    Season_Halloween,
+    #[assoc(req = Req::Season(Season::Valentines))]
+    Season_Valentines,
 }
 
+// the news doesn't touch the gameplay rng, so showing it can't change how a game plays out
 pub fn get_entry(state: &State) -> Option<NewsEntry> {
     NewsEntry::variants()
         .filter(|e| e.req().check(state))
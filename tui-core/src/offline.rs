@@ -0,0 +1,31 @@
+use crate::{State, cps::Cps, sugar_lumps};
+
+#[derive(Debug, Copy, Clone)]
+pub struct OfflineProgress {
+    pub secs: f64,
+    pub cookies: f64,
+    pub sugar_lumps: u16,
+}
+
+pub(crate) fn fast_forward(
+    state: &mut State,
+    cps: &Cps,
+    secs: f64,
+    efficiency: f64,
+) -> OfflineProgress {
+    // buffs don't keep going while the game is closed
+    let cookies = cps.total / state.buffs.cps_mult() * secs * efficiency;
+
+    state.cookies.gain_bulk(cookies);
+    state.buffs.fast_forward_secs(secs);
+    state.research.fast_forward_secs(secs);
+    state.grandmapocalypse.fast_forward_secs(secs);
+
+    let sugar_lumps = sugar_lumps::fast_forward_secs(state, secs);
+
+    OfflineProgress {
+        secs,
+        cookies,
+        sugar_lumps,
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Changeset, State};
+use cookie_clicker_tui_utils::refresh::Refresh;
+use enum_assoc::Assoc;
+use enum_fun::{Name, Variants};
+use serde::{Deserialize, Serialize};
+
+const MAX_SWAPS: u8 = 3;
+
+#[cfg(debug_assertions)]
+const REFRESH_SWAP: f64 = 10.0;
+
+#[cfg(not(debug_assertions))]
+const REFRESH_SWAP: f64 = 60.0 * 60.0;
+
+pub fn slot(
+    state: &mut State,
+    slot: SpiritSlot,
+    spirit: Option<Spirit>,
+    changeset: &mut Changeset,
+) -> bool {
+    let pantheon = &mut state.pantheon;
+
+    if pantheon.spirit(slot) == spirit {
+        return false;
+    }
+
+    if spirit.is_some() {
+        if pantheon.swaps == 0 {
+            return false;
+        }
+        pantheon.swaps -= 1;
+    }
+
+    // a spirit can only be worshipped in one slot at a time
+    if let Some(spirit) = spirit
+        && let Some(other) = pantheon.slot_of(spirit)
+    {
+        pantheon.slots[other as usize] = None;
+    }
+    pantheon.slots[slot as usize] = spirit;
+
+    apply(state, changeset);
+
+    true
+}
+
+// holobore leaves as soon as a golden cookie is clicked
+pub(crate) fn golden_cookie_clicked(state: &mut State, changeset: &mut Changeset) {
+    if let Some(slot) = state.pantheon.slot_of(Spirit::Holobore) {
+        state.pantheon.slots[slot as usize] = None;
+        apply(state, changeset);
+    }
+}
+
+// the building cost mult isn't saved with the buildings,
+// so it needs to be reapplied when they are loaded
+pub(crate) fn apply(state: &mut State, changeset: &mut Changeset) {
+    state
+        .buildings
+        .set_cost_mult(state.pantheon.building_cost_mult());
+
+    changeset.cps = true;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Pantheon {
+    slots: [Option<Spirit>; SpiritSlot::VARIANT_COUNT],
+    swaps: u8,
+    refresh: Refresh,
+}
+
+impl Pantheon {
+    pub(crate) fn new() -> Self {
+        Self {
+            slots: [None; SpiritSlot::VARIANT_COUNT],
+            swaps: MAX_SWAPS,
+            refresh: Refresh::new(REFRESH_SWAP),
+        }
+    }
+
+    pub(crate) fn tick(&mut self) {
+        if self.swaps < MAX_SWAPS && self.refresh.finish() {
+            self.swaps += 1;
+        }
+    }
+
+    pub fn spirit(&self, slot: SpiritSlot) -> Option<Spirit> {
+        self.slots[slot as usize]
+    }
+
+    pub fn slot_of(&self, spirit: Spirit) -> Option<SpiritSlot> {
+        SpiritSlot::variants().find(|s| self.spirit(*s) == Some(spirit))
+    }
+
+    pub fn swaps(&self) -> u8 {
+        self.swaps
+    }
+
+    pub fn max_swaps(&self) -> u8 {
+        MAX_SWAPS
+    }
+
+    pub fn secs_until_swap(&self) -> Option<f64> {
+        (self.swaps < MAX_SWAPS).then(|| self.refresh.until_finish_secs())
+    }
+
+    pub fn cps_mult(&self) -> f64 {
+        self.product(Spirit::cps_mults)
+    }
+
+    pub fn building_cost_mult(&self) -> f64 {
+        self.product(Spirit::building_cost_mults)
+    }
+
+    pub fn golden_cookie_freq_mult(&self) -> f64 {
+        self.product(Spirit::golden_cookie_freq_mults)
+    }
+
+    fn product(&self, f: fn(Spirit) -> [f64; SpiritSlot::VARIANT_COUNT]) -> f64 {
+        SpiritSlot::variants()
+            .filter_map(|slot| self.spirit(slot).map(|spirit| f(spirit)[slot as usize]))
+            .product()
+    }
+}
+
+impl Default for Pantheon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Name, Variants, Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum SpiritSlot {
+    Diamond,
+    Ruby,
+    Jade,
+}
+
+// each effect is listed as [diamond, ruby, jade]
+#[derive(
+    Assoc,
+    Name,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[func(pub const fn title(self) -> &'static str)]
+#[func(pub const fn cps_mults(self) -> [f64; 3])]
+#[func(pub const fn building_cost_mults(self) -> [f64; 3])]
+#[func(pub const fn golden_cookie_freq_mults(self) -> [f64; 3])]
+#[name(base = "title case")]
+pub enum Spirit {
+    #[assoc(title = "Spirit of Asceticism")]
+    #[assoc(cps_mults = [1.15, 1.10, 1.05])]
+    #[assoc(building_cost_mults = [1.0; 3], golden_cookie_freq_mults = [1.0; 3])]
+    Holobore,
+    #[assoc(title = "Spirit of Industry")]
+    #[assoc(cps_mults = [1.10, 1.06, 1.03])]
+    #[assoc(building_cost_mults = [1.0; 3], golden_cookie_freq_mults = [0.90, 0.94, 0.97])]
+    Jeremy,
+    #[assoc(title = "Spirit of Creation")]
+    #[assoc(cps_mults = [1.0; 3])]
+    #[assoc(building_cost_mults = [0.93, 0.95, 0.98], golden_cookie_freq_mults = [1.0; 3])]
+    Dotjeiess,
+    #[assoc(title = "Spirit of Festivities")]
+    #[assoc(cps_mults = [1.0; 3])]
+    #[assoc(building_cost_mults = [1.0; 3], golden_cookie_freq_mults = [1.10, 1.06, 1.03])]
+    Selebrak,
+}
@@ -0,0 +1,53 @@
+use crate::{calc, cookies::Cookies};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct Prestige {
+    level: f64,
+    heavenly_chips: f64,
+    ascensions: u32,
+    cookies_forfeited: f64,
+}
+
+impl Prestige {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+
+    pub fn heavenly_chips(&self) -> f64 {
+        self.heavenly_chips
+    }
+
+    pub fn ascensions(&self) -> u32 {
+        self.ascensions
+    }
+
+    pub fn cookies_forfeited(&self) -> f64 {
+        self.cookies_forfeited
+    }
+
+    pub(crate) fn spend_heavenly_chips(&mut self, chips: f64) {
+        self.heavenly_chips -= chips;
+    }
+
+    pub(crate) fn cookies_all_runs(&self, cookies: &Cookies) -> f64 {
+        self.cookies_forfeited + cookies.all_time()
+    }
+
+    pub(crate) fn level_after_ascension(&self, cookies: &Cookies) -> f64 {
+        calc::prestige_level(self.cookies_all_runs(cookies))
+    }
+
+    pub(crate) fn ascend(&mut self, cookies: &Cookies) {
+        let level = self.level_after_ascension(cookies);
+
+        self.heavenly_chips += level - self.level;
+        self.level = level;
+        self.ascensions = self.ascensions.saturating_add(1);
+        self.cookies_forfeited += cookies.all_time();
+    }
+}
@@ -1,10 +1,11 @@
-use crate::{Achievement, Building, Computed, GrandmapocalypsePhase, State};
+use crate::{Achievement, Building, Computed, GrandmapocalypsePhase, Season, State};
 
 #[allow(unused)]
 pub enum Req {
     Cookies(Cmp<f64>),
     CookiesAllTime(Cmp<f64>),
     CookiesAllTimeFromClicking(Cmp<f64>),
+    CookiesAllRuns(Cmp<f64>),
     BuildingCount(Building, Cmp<u16>),
     BuildingCookiesAllTime(Building, Cmp<f64>),
     ResearchCompleted(Cmp<u8>),
@@ -20,6 +21,7 @@ pub enum Req {
     GoldenCookieClicked(Cmp<usize>),
     GoldenCookieClickedAtMost1sAfterSpawn(),
     GoldenCookieClickedAtMost1sBeforeDespawn(),
+    Season(Season),
     Custom(fn(&State) -> bool),
     CustomBox(Box<dyn Fn(&State) -> bool>),
     Any(&'static [Req]),
@@ -34,6 +36,7 @@ impl Req {
             Self::Cookies(c) => c.check(state.cookies.current()),
             Self::CookiesAllTime(c) => c.check(state.cookies.all_time()),
             Self::CookiesAllTimeFromClicking(c) => c.check(state.cookies.all_time_from_clicking()),
+            Self::CookiesAllRuns(c) => c.check(state.prestige.cookies_all_runs(&state.cookies)),
             Self::BuildingCount(b, c) => c.check(state.buildings.count(*b)),
             Self::BuildingCookiesAllTime(b, c) => {
                 c.check(state.buildings.state(*b).cookies_all_time)
@@ -59,6 +62,7 @@ impl Req {
             Self::GoldenCookieClickedAtMost1sBeforeDespawn() => {
                 state.golden_cookies.clicked_one_at_most_1s_before_despawn()
             }
+            Self::Season(s) => state.seasons.is(*s),
             Self::Custom(f) => f(state),
             Self::CustomBox(f) => f(state),
             Self::Any(reqs) => reqs.iter().any(|r| r.check(state)),
@@ -94,6 +98,7 @@ impl LateReq {
         Cookies(c: Cmp<f64>);
         CookiesAllTime(c: Cmp<f64>);
         CookiesAllTimeFromClicking(c: Cmp<f64>);
+        CookiesAllRuns(c: Cmp<f64>);
         BuildingCount(b: Building, c: Cmp<u16>);
         BuildingCookiesAllTime(b: Building, c: Cmp<f64>);
         ResearchCompleted(c: Cmp<u8>);
@@ -109,6 +114,7 @@ impl LateReq {
         GoldenCookieClicked(c: Cmp<usize>);
         GoldenCookieClickedAtMost1sAfterSpawn();
         GoldenCookieClickedAtMost1sBeforeDespawn();
+        Season(s: Season);
     }
 
     pub fn check(&self, state: &State, computed: &Computed) -> bool {
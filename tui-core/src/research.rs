@@ -8,7 +8,7 @@ const REFRESH: f64 = 5.0;
 #[cfg(not(debug_assertions))]
 const REFRESH: f64 = 30.0 * 60.0;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Research {
     completed: u8,
     refresh: Option<Refresh>,
@@ -34,6 +34,12 @@ impl Research {
         }
     }
 
+    pub(crate) fn fast_forward_secs(&mut self, secs: f64) {
+        if let Some(refresh) = self.refresh.as_mut() {
+            refresh.fast_forward_secs(secs);
+        }
+    }
+
     pub(crate) fn start(&mut self) {
         self.refresh = Some(Refresh::new(REFRESH));
     }
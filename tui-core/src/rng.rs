@@ -0,0 +1,38 @@
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+// saved along with the rest of the state, so a save replays the same rolls
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub(crate) struct CoreRng(ChaCha8Rng);
+
+impl CoreRng {
+    pub(crate) fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    pub(crate) fn with_seed(seed: u64) -> Self {
+        Self(ChaCha8Rng::seed_from_u64(seed))
+    }
+}
+
+impl Default for CoreRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RngCore for CoreRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{Changeset, Season, State, Upgrade, calc};
+use enum_fun::{Name, Variants};
+use rand::seq::IndexedRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+pub(crate) const LEGACY_CPS_MULT_PER_LEVEL: f64 = 0.03;
+
+pub fn level_cost(state: &State) -> Option<f64> {
+    let santa = &state.santa;
+
+    if !state.seasons.is(Season::Christmas) || santa.level == SantaLevel::MAX {
+        return None;
+    }
+
+    Some(calc::santa_level_cost(santa.level.number()))
+}
+
+// cookie costs are paid by the caller
+pub(crate) fn level_up(state: &mut State, changeset: &mut Changeset) -> bool {
+    if level_cost(state).is_none() {
+        return false;
+    }
+
+    let santa = &mut state.santa;
+
+    santa.level = SantaLevel::VARIANTS[santa.level.number() as usize];
+
+    // every level grants a gift from the ones santa hasn't given yet
+    let candidates = Upgrade::variants()
+        .filter(|u| u.santa_gift_level().is_some_and(|l| l <= santa.level) && !santa.gifted(*u))
+        .collect::<Vec<_>>();
+
+    if let Some(upgrade) = candidates.choose(&mut state.rng).copied() {
+        santa.gifts.insert(upgrade);
+        changeset.available_upgrades = true;
+    }
+
+    changeset.cps = true;
+
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Santa {
+    level: SantaLevel,
+    gifts: BTreeSet<Upgrade>,
+    legacy: bool,
+}
+
+impl Santa {
+    pub(crate) fn new() -> Self {
+        Self {
+            level: SantaLevel::FestiveTestTube,
+            gifts: BTreeSet::new(),
+            legacy: false,
+        }
+    }
+
+    pub fn level(&self) -> SantaLevel {
+        self.level
+    }
+
+    pub fn gifted(&self, upgrade: Upgrade) -> bool {
+        self.gifts.contains(&upgrade)
+    }
+
+    pub(crate) fn cps_mult(&self) -> f64 {
+        if self.legacy {
+            1.0 + LEGACY_CPS_MULT_PER_LEVEL * self.level.number() as f64
+        } else {
+            1.0
+        }
+    }
+
+    pub(crate) fn enable_legacy(&mut self, changeset: &mut Changeset) {
+        self.legacy = true;
+        changeset.cps = true;
+    }
+
+    // santa keeps growing across ascensions, the bought gifts don't
+    pub(crate) fn ascend(&mut self) {
+        self.legacy = false;
+    }
+}
+
+impl Default for Santa {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(
+    Name, Variants, Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum SantaLevel {
+    FestiveTestTube,
+    FestiveOrnament,
+    FestiveWreath,
+    FestiveTree,
+    FestivePresent,
+    FestiveElfFetus,
+    ElfToddler,
+    Elfling,
+    YoungElf,
+    BulkyElf,
+    Nick,
+    SantaClaus,
+    ElderSanta,
+    TrueSanta,
+    FinalClaus,
+}
+
+impl SantaLevel {
+    pub const MAX: Self = Self::FinalClaus;
+
+    // levels are counted from 1, like in the game
+    pub const fn number(self) -> u8 {
+        self as u8 + 1
+    }
+}
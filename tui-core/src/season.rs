@@ -0,0 +1,173 @@
+use crate::{Changeset, State, Upgrade, spawner::Spawner};
+use cookie_clicker_tui_utils::refresh::Refresh;
+use enum_fun::{Name, Variants};
+use rand::{Rng, seq::IndexedRandom};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+#[cfg(debug_assertions)]
+const SEASON_DURATION_SECS: f64 = 60.0;
+
+#[cfg(not(debug_assertions))]
+const SEASON_DURATION_SECS: f64 = 24.0 * 60.0 * 60.0;
+
+const REINDEER_TMIN_SECS: f64 = 180.0;
+const REINDEER_TMAX_SECS: f64 = 360.0;
+
+const REINDEER_DROP_CHANCE: f64 = 0.2;
+const HALLOWEEN_WRINKLER_DROP_CHANCE: f64 = 0.1;
+const HALLOWEEN_SHINY_WRINKLER_DROP_CHANCE: f64 = 1.0;
+const VALENTINES_GOLDEN_COOKIE_DROP_CHANCE: f64 = 0.15;
+const EASTER_GOLDEN_COOKIE_DROP_CHANCE: f64 = 0.1;
+const EASTER_WRINKLER_DROP_CHANCE: f64 = 0.05;
+
+// can't be a method since reindeer are spawned into &mut GoldenCookies,
+// which is a sibling field of state
+pub fn tick(state: &mut State, changeset: &mut Changeset) {
+    let seasons = &mut state.seasons;
+
+    let Some(season) = seasons.current else {
+        return;
+    };
+
+    if seasons.refresh.finish() {
+        seasons.current = None;
+        changeset.available_upgrades = true;
+        return;
+    }
+
+    if season == Season::Christmas && seasons.reindeer_spawner.spawn(&mut state.rng) {
+        state.golden_cookies.spawn_reindeer(&mut state.rng);
+    }
+}
+
+pub(crate) fn switch(state: &mut State, season: Season, changeset: &mut Changeset) {
+    let seasons = &mut state.seasons;
+
+    seasons.current = Some(season);
+    seasons.refresh = Refresh::new(SEASON_DURATION_SECS);
+    seasons.switches = seasons.switches.saturating_add(1);
+
+    changeset.available_upgrades = true;
+}
+
+pub(crate) fn roll_drop(state: &mut State, source: DropSource, changeset: &mut Changeset) {
+    let Some(season) = state.seasons.current else {
+        return;
+    };
+
+    let chance = match (season, source) {
+        (Season::Christmas, DropSource::Reindeer) => REINDEER_DROP_CHANCE,
+        (Season::Halloween, DropSource::Wrinkler(false)) => HALLOWEEN_WRINKLER_DROP_CHANCE,
+        (Season::Halloween, DropSource::Wrinkler(true)) => HALLOWEEN_SHINY_WRINKLER_DROP_CHANCE,
+        (Season::Valentines, DropSource::GoldenCookie) => VALENTINES_GOLDEN_COOKIE_DROP_CHANCE,
+        (Season::Easter, DropSource::GoldenCookie) => EASTER_GOLDEN_COOKIE_DROP_CHANCE,
+        (Season::Easter, DropSource::Wrinkler(_)) => EASTER_WRINKLER_DROP_CHANCE,
+        _ => return,
+    };
+
+    if state.rng.random::<f64>() >= chance {
+        return;
+    }
+
+    let candidates = Upgrade::variants()
+        .filter(|u| u.season_drop() == Some(season) && !state.seasons.dropped(*u))
+        .collect::<Vec<_>>();
+
+    if let Some(upgrade) = candidates.choose(&mut state.rng).copied() {
+        state.seasons.dropped.insert(upgrade);
+        changeset.available_upgrades = true;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Seasons {
+    current: Option<Season>,
+    refresh: Refresh,
+    switches: usize,
+    dropped: BTreeSet<Upgrade>,
+    reindeer_spawner: Spawner,
+}
+
+impl Seasons {
+    pub(crate) fn new() -> Self {
+        Self {
+            current: None,
+            refresh: Refresh::new(SEASON_DURATION_SECS),
+            switches: 0,
+            dropped: BTreeSet::new(),
+            reindeer_spawner: Spawner::new(REINDEER_TMIN_SECS, REINDEER_TMAX_SECS),
+        }
+    }
+
+    pub fn current(&self) -> Option<Season> {
+        self.current
+    }
+
+    pub fn is(&self, season: Season) -> bool {
+        self.current == Some(season)
+    }
+
+    pub fn secs_left(&self) -> Option<f64> {
+        self.current.map(|_| self.refresh.until_finish_secs())
+    }
+
+    pub fn switches(&self) -> usize {
+        self.switches
+    }
+
+    pub fn dropped(&self, upgrade: Upgrade) -> bool {
+        self.dropped.contains(&upgrade)
+    }
+
+    pub fn dropped_count(&self, season: Season) -> usize {
+        self.dropped
+            .iter()
+            .filter(|u| u.season_drop() == Some(season))
+            .count()
+    }
+
+    pub fn dropped_all(&self, season: Season) -> bool {
+        Upgrade::variants()
+            .filter(|u| u.season_drop() == Some(season))
+            .all(|u| self.dropped(u))
+    }
+
+    pub(crate) fn modify_reindeer_spawning(&mut self, f: impl FnOnce(&mut f64, &mut f64)) {
+        self.reindeer_spawner.modify(f);
+    }
+
+    // the season and its drops carry over, the switch price and
+    // the bought cookies don't
+    pub(crate) fn ascend(&mut self) {
+        self.switches = 0;
+        self.reindeer_spawner = Spawner::new(REINDEER_TMIN_SECS, REINDEER_TMAX_SECS);
+    }
+}
+
+impl Default for Seasons {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(
+    Name, Variants, Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[name(base = "title case")]
+pub enum Season {
+    BusinessDay,
+    Christmas,
+    Easter,
+    Halloween,
+    #[name(base = "Valentine's Day")]
+    Valentines,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum DropSource {
+    GoldenCookie,
+    Reindeer,
+    // whether the popped wrinkler was shiny
+    Wrinkler(bool),
+}
@@ -1,8 +1,9 @@
 use crate::macros;
 use cookie_clicker_tui_utils::frames::FPS;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Spawner {
     state: SpawnerState,
     computed: SpawnerComputed,
@@ -13,6 +14,7 @@ impl Spawner {
         Self::from_state(SpawnerState {
             tmin_secs,
             tmax_secs,
+            n: 0.0,
         })
     }
 
@@ -21,8 +23,13 @@ impl Spawner {
         Self { state, computed }
     }
 
-    pub fn spawn(&mut self) -> bool {
-        self.computed.spawn()
+    pub fn spawn(&mut self, rng: &mut impl Rng) -> bool {
+        self.spawn_with_freq_mult(1.0, rng)
+    }
+
+    // the mult shortens the spawn interval without touching the saved timings
+    pub fn spawn_with_freq_mult(&mut self, freq_mult: f64, rng: &mut impl Rng) -> bool {
+        self.computed.spawn(&mut self.state.n, freq_mult, rng)
     }
 
     pub fn modify(&mut self, f: impl FnOnce(&mut f64, &mut f64)) {
@@ -35,17 +42,19 @@ impl Spawner {
 macros::serialize_via_state!(Spawner => SpawnerState as |s| s.state);
 macros::deserialize_via_state!(Spawner => SpawnerState as Spawner::from_state);
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 struct SpawnerState {
     tmin_secs: f64,
     tmax_secs: f64,
+    // frames since the last spawn
+    #[serde(default)]
+    n: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct SpawnerComputed {
     tmin: f64,
     tmax: f64,
-    n: f64,
     #[cfg(debug_assertions)]
     _n_last_hit: Option<f64>,
     #[cfg(debug_assertions)]
@@ -57,7 +66,6 @@ impl SpawnerComputed {
         Self {
             tmin: state.tmin_secs * FPS,
             tmax: state.tmax_secs * FPS,
-            n: 0.0,
             #[cfg(debug_assertions)]
             _n_last_hit: None,
             #[cfg(debug_assertions)]
@@ -65,23 +73,25 @@ impl SpawnerComputed {
         }
     }
 
-    fn spawn(&mut self) -> bool {
-        let prob = f64::max(0.0, (self.n - self.tmin) / (self.tmax - self.tmin)).powi(5);
+    fn spawn(&mut self, n: &mut f64, freq_mult: f64, rng: &mut impl Rng) -> bool {
+        let tmin = self.tmin / freq_mult;
+        let tmax = self.tmax / freq_mult;
+        let prob = f64::max(0.0, (*n - tmin) / (tmax - tmin)).powi(5);
 
         #[cfg(debug_assertions)]
         {
             self._prob = prob;
         }
 
-        if rand::random::<f64>() < prob {
+        if rng.random::<f64>() < prob {
             #[cfg(debug_assertions)]
             {
-                self._n_last_hit = Some(self.n);
+                self._n_last_hit = Some(*n);
             }
-            self.n = 0.0;
+            *n = 0.0;
             true
         } else {
-            self.n += 1.0;
+            *n += 1.0;
             false
         }
     }
@@ -0,0 +1,290 @@
+use crate::Building;
+use cookie_clicker_tui_utils::{enum_map, refresh::Refresh};
+use enum_assoc::Assoc;
+use enum_fun::{Name, Variants};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+pub const HISTORY_LEN: usize = 60;
+
+const MIN_PRICE: f64 = 1.0;
+
+#[cfg(debug_assertions)]
+const REFRESH: f64 = 5.0;
+
+#[cfg(not(debug_assertions))]
+const REFRESH: f64 = 60.0;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StockMarket {
+    rng: u64,
+    goods: GoodMap<GoodState>,
+    refresh: Refresh,
+}
+
+impl StockMarket {
+    pub(crate) fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    pub(crate) fn with_seed(seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let goods = GoodMap::new(|good| GoodState::new(good, &mut rng));
+
+        Self {
+            rng: rng.random(),
+            goods,
+            refresh: Refresh::new(REFRESH),
+        }
+    }
+
+    pub(crate) fn tick(&mut self, bank_level: u16) {
+        if bank_level == 0 || !self.refresh.finish() {
+            return;
+        }
+
+        self.step(bank_level);
+    }
+
+    pub fn good(&self, good: Good) -> &GoodState {
+        self.goods.get(good)
+    }
+
+    pub fn secs_until_tick(&self) -> f64 {
+        self.refresh.until_finish_secs()
+    }
+
+    pub(crate) fn buy(&mut self, good: Good, count: u16, paid: f64) {
+        let state = self.goods.get_mut(good);
+        state.owned = state.owned.saturating_add(count);
+        state.paid += paid;
+    }
+
+    // returns what was paid for the sold goods, at their average purchase price
+    pub(crate) fn sell(&mut self, good: Good, count: u16) -> f64 {
+        let state = self.goods.get_mut(good);
+        let count = count.min(state.owned);
+        if count == 0 {
+            return 0.0;
+        }
+
+        let paid = state.paid * count as f64 / state.owned as f64;
+        state.owned -= count;
+        state.paid -= paid;
+
+        paid
+    }
+
+    fn step(&mut self, bank_level: u16) {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.rng);
+
+        for good in Good::variants() {
+            self.goods.get_mut(good).step(good, bank_level, &mut rng);
+        }
+
+        self.rng = rng.random();
+    }
+}
+
+impl Default for StockMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodState {
+    price: f64,
+    delta: f64,
+    mode: MarketMode,
+    mode_ticks_left: u16,
+    owned: u16,
+    // what the owned goods cost in total, so that only profits count towards all time cookies
+    #[serde(default)]
+    paid: f64,
+    history: VecDeque<f64>,
+}
+
+impl GoodState {
+    fn new(good: Good, rng: &mut impl Rng) -> Self {
+        let price = good.resting_price(1) + rng.random_range(-5.0..5.0);
+
+        Self {
+            price,
+            delta: 0.0,
+            mode: MarketMode::roll(rng),
+            mode_ticks_left: rng.random_range(10..=60),
+            owned: 0,
+            paid: 0.0,
+            history: VecDeque::from([price]),
+        }
+    }
+
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    pub fn mode(&self) -> MarketMode {
+        self.mode
+    }
+
+    pub fn owned(&self) -> u16 {
+        self.owned
+    }
+
+    pub fn history(&self) -> impl Iterator<Item = f64> {
+        self.history.iter().copied()
+    }
+
+    fn step(&mut self, good: Good, bank_level: u16, rng: &mut impl Rng) {
+        // prices are always slowly pulled back towards their resting price
+        self.price += (good.resting_price(bank_level) - self.price) * 0.01;
+        self.delta *= 0.97;
+
+        match self.mode {
+            MarketMode::Stable => {
+                self.delta *= 0.95;
+                self.delta += rng.random_range(-0.025..0.025);
+            }
+            MarketMode::Rising => {
+                self.delta += rng.random_range(-0.005..0.015);
+            }
+            MarketMode::Falling => {
+                self.delta -= rng.random_range(-0.005..0.015);
+            }
+            MarketMode::Chaotic => {
+                self.delta += rng.random_range(-0.15..0.15);
+                if rng.random::<f64>() < 0.1 {
+                    self.price *= rng.random_range(0.8..1.2);
+                }
+            }
+        }
+
+        self.price = f64::max(
+            self.price + self.delta + rng.random_range(-0.5..0.5),
+            MIN_PRICE,
+        );
+
+        self.mode_ticks_left = self.mode_ticks_left.saturating_sub(1);
+        if self.mode_ticks_left == 0 {
+            self.mode = MarketMode::roll(rng);
+            self.mode_ticks_left = rng.random_range(10..=60);
+        }
+
+        self.history.push_back(self.price);
+        if self.history.len() > HISTORY_LEN {
+            self.history.pop_front();
+        }
+    }
+}
+
+#[derive(
+    Assoc,
+    Name,
+    Variants,
+    Serialize,
+    Deserialize,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[func(pub const fn building(self) -> Building)]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum Good {
+    #[assoc(building = Building::Farm)]
+    Cereals,
+    #[assoc(building = Building::Mine)]
+    Chocolate,
+    #[assoc(building = Building::Factory)]
+    Butter,
+    #[assoc(building = Building::Bank)]
+    Sugar,
+    #[assoc(building = Building::Temple)]
+    Nuts,
+    #[assoc(building = Building::WizardTower)]
+    Salt,
+    #[assoc(building = Building::Shipment)]
+    Vanilla,
+    #[assoc(building = Building::AlchemyLab)]
+    Eggs,
+    #[assoc(building = Building::Portal)]
+    Cinnamon,
+    #[assoc(building = Building::TimeMachine)]
+    Cream,
+}
+
+impl Good {
+    fn resting_price(self, bank_level: u16) -> f64 {
+        10.0 + 10.0 * self as u16 as f64 + bank_level.saturating_sub(1) as f64
+    }
+}
+
+enum_map! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    struct GoodMap of Good {
+        cereals: Cereals,
+        chocolate: Chocolate,
+        butter: Butter,
+        sugar: Sugar,
+        nuts: Nuts,
+        salt: Salt,
+        vanilla: Vanilla,
+        eggs: Eggs,
+        cinnamon: Cinnamon,
+        cream: Cream,
+    }
+}
+
+#[derive(Name, Variants, Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[name(base = "title case")]
+#[name(extra(lower = "title case lower"))]
+pub enum MarketMode {
+    Stable,
+    Rising,
+    Falling,
+    Chaotic,
+}
+
+impl MarketMode {
+    fn roll(rng: &mut impl Rng) -> Self {
+        Self::VARIANTS[rng.random_range(0..Self::VARIANT_COUNT)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prices(market: &StockMarket) -> Vec<f64> {
+        Good::variants().map(|g| market.good(g).price()).collect()
+    }
+
+    #[test]
+    fn deterministic_under_seed() {
+        let mut a = StockMarket::with_seed(42);
+        let mut b = StockMarket::with_seed(42);
+        let mut c = StockMarket::with_seed(43);
+
+        for _ in 0..500 {
+            a.step(1);
+            b.step(1);
+            c.step(1);
+        }
+
+        assert_eq!(prices(&a), prices(&b));
+        assert_ne!(prices(&a), prices(&c));
+        assert!(prices(&a).iter().all(|p| *p >= MIN_PRICE));
+    }
+}
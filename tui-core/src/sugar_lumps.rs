@@ -1,14 +1,26 @@
 use crate::{
-    Changeset, State, macros,
+    Building, Changeset, GrandmapocalypsePhase, State, calc, macros,
     req::{Cmp, Req},
 };
 use cookie_clicker_tui_utils::{num, refresh::Refresh};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 const REFRESH_LOCKED: f64 = 10.0;
-const REFRESH_GROW: f64 = 10.0;
 const UNLOCK_REQ: Req = Req::CookiesAllTime(Cmp::AboveOrEq(1.0 * num::BILLION));
 
+// the time until a lump falls on its own, an hour after it becomes overripe
+#[cfg(debug_assertions)]
+const LIFETIME_SECS: f64 = 30.0;
+
+#[cfg(not(debug_assertions))]
+const LIFETIME_SECS: f64 = 25.0 * 60.0 * 60.0;
+
+const MATURE_RATIO: f64 = 20.0 / 25.0;
+const RIPE_RATIO: f64 = 23.0 / 25.0;
+const OVERRIPE_RATIO: f64 = 24.0 / 25.0;
+const BOTCHED_CHANCE: f64 = 0.5;
+
 pub fn tick(state: &mut State, changeset: &mut Changeset) {
     match &mut state.sugar_lumps.0 {
         SugarLumpsState::Locked { refresh } => {
@@ -16,7 +28,8 @@ pub fn tick(state: &mut State, changeset: &mut Changeset) {
                 if UNLOCK_REQ.check(state) {
                     state.sugar_lumps.0 = SugarLumpsState::Unlocked {
                         count: 0,
-                        refresh: Refresh::new(REFRESH_GROW),
+                        kind: SugarLumpKind::Normal,
+                        refresh: new_lump_refresh(state),
                     };
                     changeset.sugar_lumps_unlocked = true;
                     // borrow again to prevent errors
@@ -25,25 +38,86 @@ pub fn tick(state: &mut State, changeset: &mut Changeset) {
                 }
             }
         }
-        SugarLumpsState::Unlocked { count, refresh } => {
-            if refresh.finish()
-                && let Some(next_count) = count.checked_add(1)
-            {
-                *count = next_count;
-                refresh.reset();
+        SugarLumpsState::Unlocked { refresh, .. } => {
+            // overripe lumps fall on their own
+            if refresh.finish() {
+                let kind = state.sugar_lumps.kind_or_normal();
+                state.sugar_lumps.gain(kind.harvest_yield(&mut state.rng));
+                start_new_lump(state);
             }
         }
     }
 }
 
-#[derive(Debug)]
+pub fn harvest(state: &mut State) -> Option<u16> {
+    let stage = state.sugar_lumps.stage()?;
+    let kind = state.sugar_lumps.kind_or_normal();
+
+    let lumps = match stage {
+        SugarLumpStage::Growing => return None,
+        SugarLumpStage::Mature if state.rng.random::<f64>() < BOTCHED_CHANCE => 0,
+        SugarLumpStage::Mature | SugarLumpStage::Ripe | SugarLumpStage::Overripe => {
+            kind.harvest_yield(&mut state.rng)
+        }
+    };
+
+    state.sugar_lumps.gain(lumps);
+    start_new_lump(state);
+
+    Some(lumps)
+}
+
+pub fn fast_forward_secs(state: &mut State, secs: f64) -> u16 {
+    let SugarLumpsState::Unlocked { refresh, .. } = &mut state.sugar_lumps.0 else {
+        return 0;
+    };
+
+    let fallen = refresh.fast_forward_secs_cyclic(secs);
+    let before = state.sugar_lumps.count();
+
+    for _ in 0..fallen {
+        let kind = state.sugar_lumps.kind_or_normal();
+        state.sugar_lumps.gain(kind.harvest_yield(&mut state.rng));
+
+        let next_kind = SugarLumpKind::roll(state);
+        if let SugarLumpsState::Unlocked { kind, .. } = &mut state.sugar_lumps.0 {
+            *kind = next_kind;
+        }
+    }
+
+    state.sugar_lumps.count() - before
+}
+
+fn start_new_lump(state: &mut State) {
+    let next_kind = SugarLumpKind::roll(state);
+    let next_refresh = new_lump_refresh(state);
+
+    if let SugarLumpsState::Unlocked { kind, refresh, .. } = &mut state.sugar_lumps.0 {
+        *kind = next_kind;
+        *refresh = next_refresh;
+    }
+}
+
+fn new_lump_refresh(state: &State) -> Refresh {
+    let grandmas = state.buildings.count(Building::Grandma);
+    Refresh::new(LIFETIME_SECS * calc::sugar_lump_ripening_mult(grandmas))
+}
+
+#[derive(Debug, Clone)]
 pub struct SugarLumps(SugarLumpsState);
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "state")]
 enum SugarLumpsState {
-    Locked { refresh: Refresh },
-    Unlocked { count: u16, refresh: Refresh },
+    Locked {
+        refresh: Refresh,
+    },
+    Unlocked {
+        count: u16,
+        #[serde(default)]
+        kind: SugarLumpKind,
+        refresh: Refresh,
+    },
 }
 
 impl SugarLumps {
@@ -60,10 +134,113 @@ impl SugarLumps {
         }
     }
 
+    pub fn kind(&self) -> Option<SugarLumpKind> {
+        match &self.0 {
+            SugarLumpsState::Locked { .. } => None,
+            SugarLumpsState::Unlocked { kind, .. } => Some(*kind),
+        }
+    }
+
+    pub fn stage(&self) -> Option<SugarLumpStage> {
+        match &self.0 {
+            SugarLumpsState::Locked { .. } => None,
+            SugarLumpsState::Unlocked { refresh, .. } => {
+                let ratio = refresh.cur() / refresh.max();
+                Some(if ratio >= OVERRIPE_RATIO {
+                    SugarLumpStage::Overripe
+                } else if ratio >= RIPE_RATIO {
+                    SugarLumpStage::Ripe
+                } else if ratio >= MATURE_RATIO {
+                    SugarLumpStage::Mature
+                } else {
+                    SugarLumpStage::Growing
+                })
+            }
+        }
+    }
+
+    pub fn harvestable(&self) -> bool {
+        self.stage()
+            .is_some_and(|stage| stage != SugarLumpStage::Growing)
+    }
+
+    pub(crate) fn spend(&mut self, amount: u16) {
+        if let SugarLumpsState::Unlocked { count, .. } = &mut self.0 {
+            *count = count.saturating_sub(amount);
+        }
+    }
+
     pub fn unlocked(&self) -> bool {
         matches!(self.0, SugarLumpsState::Unlocked { .. })
     }
+
+    fn gain(&mut self, amount: u16) {
+        if let SugarLumpsState::Unlocked { count, .. } = &mut self.0 {
+            *count = count.saturating_add(amount);
+        }
+    }
+
+    fn kind_or_normal(&self) -> SugarLumpKind {
+        self.kind().unwrap_or_default()
+    }
 }
 
 macros::serialize_via_state!(SugarLumps => SugarLumpsState as |s| s.0);
 macros::deserialize_via_state!(SugarLumps => SugarLumpsState as SugarLumps);
+
+#[derive(Serialize, Deserialize, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SugarLumpKind {
+    #[default]
+    Normal,
+    Bifurcated,
+    Golden,
+    Meaty,
+    Caramelized,
+}
+
+impl SugarLumpKind {
+    fn roll(state: &mut State) -> Self {
+        let meaty_chance = match state.grandmapocalypse.phase() {
+            None => 0.0,
+            Some(GrandmapocalypsePhase::Awoken) => 0.1,
+            Some(GrandmapocalypsePhase::Displeased) => 0.2,
+            Some(GrandmapocalypsePhase::Angered) => 0.3,
+        };
+
+        let chances = [
+            (Self::Golden, 0.002),
+            (Self::Caramelized, 0.02),
+            (Self::Meaty, meaty_chance),
+            (Self::Bifurcated, 0.1),
+        ];
+
+        let mut roll = state.rng.random::<f64>();
+
+        for (kind, chance) in chances {
+            if roll < chance {
+                return kind;
+            }
+            roll -= chance;
+        }
+
+        Self::Normal
+    }
+
+    fn harvest_yield(self, rng: &mut impl Rng) -> u16 {
+        match self {
+            Self::Normal => 1,
+            Self::Bifurcated => rng.random_range(1..=2),
+            Self::Golden => rng.random_range(2..=7),
+            Self::Meaty => rng.random_range(0..=2),
+            Self::Caramelized => rng.random_range(1..=3),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SugarLumpStage {
+    Growing,
+    Mature,
+    Ripe,
+    Overripe,
+}
@@ -1,7 +1,7 @@
 use crate::{Changeset, building::Buildings, click::Click};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ThousandFingers {
     enabled: bool,
     mult: f64,
@@ -1,29 +1,43 @@
 mod click_and_cursor;
+mod dragon;
 mod effect_info;
 mod grandma_job;
 mod kitten;
+mod luck;
 mod research;
+mod santa;
+mod season;
 mod switch;
+mod synergy;
 mod tiered;
+mod wrinkler_boost;
 
 pub use effect_info::*;
 
 use self::{
     click_and_cursor::{ClickAndCursor, ClickAndCursorMode},
+    dragon::DragonEgg,
     grandma_job::GrandmaJob,
     kitten::Kitten,
+    luck::{Luck, LuckMode},
     research::Research,
+    santa::{SANTA_GIFT_COST, SANTAS_DOMINION_COST, SantaGift, SantaGiftEffect},
+    season::{EASTER_EGG_COST, SeasonDrop, SeasonSwitcher},
     switch::Switch,
+    synergy::Synergy,
     tiered::Tiered,
+    wrinkler_boost::{WrinklerBoost, WrinklerBoostMode},
+};
+use crate::{
+    Building, Changeset, Cost, CostResolved, Season, State, cps::Cps, req::Req, santa::SantaLevel,
 };
-use crate::{Building, Changeset, Cost, State, cps::Cps, req::Req};
 use cookie_clicker_tui_utils::{num, refresh::Refresh};
 use enum_assoc::Assoc;
 use enum_fun::{Name, Variants};
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeSet, ops::Deref};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OwnedUpgrades(BTreeSet<Upgrade>);
 
 impl OwnedUpgrades {
@@ -865,6 +879,46 @@ pub enum Upgrade {
     #[assoc(class = UpgradeClass::Kitten(Kitten::new(350, 0.105, 900.0 * num::QUINDECILLION)))]
     KittenStrategists,
     /* -------------------------------------------------------------------------- */
+    /*                                  Synergies                                 */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Mine, Building::WizardTower)))]
+    SeismicMagic,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Mine, Building::Shipment)))]
+    FossilFuels,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Mine, Building::AlchemyLab)))]
+    PrimordialOres,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::WizardTower, Building::AlchemyLab)))]
+    ArcaneKnowledge,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Farm, Building::Portal)))]
+    InfernalCrops,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Farm, Building::TimeMachine)))]
+    FutureAlmanacs,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Farm, Building::Temple)))]
+    RainPrayer,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Bank, Building::Portal)))]
+    ContractsFromBeyond,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Factory, Building::Bank)))]
+    PrintingPresses,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Temple, Building::Portal)))]
+    Paganism,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Factory, Building::AntimatterCondenser)))]
+    QuantumElectronics,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Shipment, Building::TimeMachine)))]
+    RelativisticParsecSkipping,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::Bank, Building::AntimatterCondenser)))]
+    ExtraPhysicsFunding,
+    #[assoc(class = UpgradeClass::Synergy(Synergy::new(Building::WizardTower, Building::Prism)))]
+    LightMagic,
+    /* -------------------------------------------------------------------------- */
+    /*                               Golden Cookies                               */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(class = UpgradeClass::Luck(Luck::new(7, LuckMode::Frequency, 777.777777 * num::MILLION)))]
+    LuckyDay,
+    #[assoc(class = UpgradeClass::Luck(Luck::new(27, LuckMode::Frequency, 77.777777777 * num::BILLION)))]
+    Serendipity,
+    #[assoc(class = UpgradeClass::Luck(Luck::new(77, LuckMode::EffectDuration, 77.777777777777 * num::TRILLION)))]
+    GetLucky,
+    /* -------------------------------------------------------------------------- */
     /*                          Research/Grandmapocalyse                          */
     /* -------------------------------------------------------------------------- */
     #[assoc(class = UpgradeClass::Research(Research::BingoCenterResearchFacility))]
@@ -891,6 +945,15 @@ pub enum Upgrade {
     #[assoc(class = UpgradeClass::Research(Research::SacrificialRollingPins))]
     SacrificialRollingPins,
     /* -------------------------------------------------------------------------- */
+    /*                                  Wrinklers                                 */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(class = UpgradeClass::WrinklerBoost(WrinklerBoost::new(1, WrinklerBoostMode::PopMult(1.05), 666.666 * num::TRILLION)))]
+    Wrinklerspawn,
+    #[assoc(class = UpgradeClass::WrinklerBoost(WrinklerBoost::new(10, WrinklerBoostMode::MaxSize(2), 444.444 * num::QUADRILLION)))]
+    ElderSpice,
+    #[assoc(class = UpgradeClass::WrinklerBoost(WrinklerBoost::new(50, WrinklerBoostMode::PopMult(1.05), 6.666 * num::QUINTILLION)))]
+    SacrilegiousCorruption,
+    /* -------------------------------------------------------------------------- */
     /*                                  Switches                                  */
     /* -------------------------------------------------------------------------- */
     #[assoc(class = UpgradeClass::Switch(Switch::ElderPledge))]
@@ -899,6 +962,105 @@ pub enum Upgrade {
     ElderCovenant,
     #[assoc(class = UpgradeClass::Switch(Switch::RevokeElderCovenant))]
     RevokeElderCovenant,
+    /* -------------------------------------------------------------------------- */
+    /*                                   Seasons                                  */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(class = UpgradeClass::SeasonSwitcher(SeasonSwitcher::new(Season::Christmas)))]
+    FestiveBiscuit,
+    #[assoc(class = UpgradeClass::SeasonSwitcher(SeasonSwitcher::new(Season::Halloween)))]
+    GhostlyBiscuit,
+    #[assoc(class = UpgradeClass::SeasonSwitcher(SeasonSwitcher::new(Season::Valentines)))]
+    LovesickBiscuit,
+    #[name(base = "Fool's Biscuit")]
+    #[assoc(class = UpgradeClass::SeasonSwitcher(SeasonSwitcher::new(Season::BusinessDay)))]
+    FoolsBiscuit,
+    #[assoc(class = UpgradeClass::SeasonSwitcher(SeasonSwitcher::new(Season::Easter)))]
+    BunnyBiscuit,
+    /* -------------------------------------------------------------------------- */
+    /*                               Seasonal Drops                               */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::ChristmasTreeBiscuits, Season::Christmas, 1.02, Cost::Cookies(252.525 * num::BILLION))))]
+    ChristmasTreeBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::SnowflakeBiscuits, Season::Christmas, 1.02, Cost::Cookies(252.525 * num::BILLION))))]
+    SnowflakeBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::SnowmanBiscuits, Season::Christmas, 1.02, Cost::Cookies(252.525 * num::BILLION))))]
+    SnowmanBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::HollyBiscuits, Season::Christmas, 1.02, Cost::Cookies(252.525 * num::BILLION))))]
+    HollyBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::CandyCaneBiscuits, Season::Christmas, 1.02, Cost::Cookies(252.525 * num::BILLION))))]
+    CandyCaneBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::BellBiscuits, Season::Christmas, 1.02, Cost::Cookies(252.525 * num::BILLION))))]
+    BellBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::PresentBiscuits, Season::Christmas, 1.02, Cost::Cookies(252.525 * num::BILLION))))]
+    PresentBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::SkullCookies, Season::Halloween, 1.02, Cost::Cookies(444.444 * num::BILLION))))]
+    SkullCookies,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::GhostCookies, Season::Halloween, 1.02, Cost::Cookies(444.444 * num::BILLION))))]
+    GhostCookies,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::BatCookies, Season::Halloween, 1.02, Cost::Cookies(444.444 * num::BILLION))))]
+    BatCookies,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::SlimeCookies, Season::Halloween, 1.02, Cost::Cookies(444.444 * num::BILLION))))]
+    SlimeCookies,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::PumpkinCookies, Season::Halloween, 1.02, Cost::Cookies(444.444 * num::BILLION))))]
+    PumpkinCookies,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::EyeballCookies, Season::Halloween, 1.02, Cost::Cookies(444.444 * num::BILLION))))]
+    EyeballCookies,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::SpiderCookies, Season::Halloween, 1.02, Cost::Cookies(444.444 * num::BILLION))))]
+    SpiderCookies,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::PureHeartBiscuits, Season::Valentines, 1.02, Cost::Cookies(1.0 * num::MILLION))))]
+    PureHeartBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::ArdentHeartBiscuits, Season::Valentines, 1.02, Cost::Cookies(1.0 * num::BILLION))))]
+    ArdentHeartBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::SourHeartBiscuits, Season::Valentines, 1.02, Cost::Cookies(1.0 * num::TRILLION))))]
+    SourHeartBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::WeepingHeartBiscuits, Season::Valentines, 1.02, Cost::Cookies(1.0 * num::QUADRILLION))))]
+    WeepingHeartBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::GoldenHeartBiscuits, Season::Valentines, 1.02, Cost::Cookies(1.0 * num::QUINTILLION))))]
+    GoldenHeartBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::EternalHeartBiscuits, Season::Valentines, 1.02, Cost::Cookies(1.0 * num::SEXTILLION))))]
+    EternalHeartBiscuits,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::ChickenEgg, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    ChickenEgg,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::DuckEgg, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    DuckEgg,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::TurkeyEgg, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    TurkeyEgg,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::QuailEgg, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    QuailEgg,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::RobinEgg, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    RobinEgg,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::OstrichEgg, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    OstrichEgg,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::CassowaryEgg, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    CassowaryEgg,
+    #[assoc(class = UpgradeClass::SeasonDrop(SeasonDrop::new(Upgrade::SalmonRoe, Season::Easter, 1.01, EASTER_EGG_COST)))]
+    SalmonRoe,
+    /* -------------------------------------------------------------------------- */
+    /*                                   Santa                                    */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(class = UpgradeClass::SantaGift(SantaGift::new(Upgrade::IncreasedMerriness, SantaLevel::FestiveOrnament, SantaGiftEffect::CpsMult(1.15), SANTA_GIFT_COST)))]
+    IncreasedMerriness,
+    #[assoc(class = UpgradeClass::SantaGift(SantaGift::new(Upgrade::ImprovedJolliness, SantaLevel::FestiveOrnament, SantaGiftEffect::CpsMult(1.15), SANTA_GIFT_COST)))]
+    ImprovedJolliness,
+    #[assoc(class = UpgradeClass::SantaGift(SantaGift::new(Upgrade::ALumpOfCoal, SantaLevel::FestiveOrnament, SantaGiftEffect::CpsMult(1.01), SANTA_GIFT_COST)))]
+    #[name(base = "A Lump of Coal")]
+    ALumpOfCoal,
+    #[assoc(class = UpgradeClass::SantaGift(SantaGift::new(Upgrade::AnItchySweater, SantaLevel::FestiveOrnament, SantaGiftEffect::CpsMult(1.01), SANTA_GIFT_COST)))]
+    AnItchySweater,
+    #[assoc(class = UpgradeClass::SantaGift(SantaGift::new(Upgrade::ReindeerBakingGrounds, SantaLevel::FestiveOrnament, SantaGiftEffect::ReindeerFrequency, SANTA_GIFT_COST)))]
+    ReindeerBakingGrounds,
+    #[assoc(class = UpgradeClass::SantaGift(SantaGift::new(Upgrade::SantasLegacy, SantaLevel::FestiveOrnament, SantaGiftEffect::Legacy, SANTA_GIFT_COST)))]
+    #[name(base = "Santa's Legacy")]
+    SantasLegacy,
+    #[assoc(class = UpgradeClass::SantaGift(SantaGift::new(Upgrade::SantasDominion, SantaLevel::FinalClaus, SantaGiftEffect::CpsMult(1.2), SANTAS_DOMINION_COST)))]
+    #[name(base = "Santa's Dominion")]
+    SantasDominion,
+    /* -------------------------------------------------------------------------- */
+    /*                                   Dragon                                   */
+    /* -------------------------------------------------------------------------- */
+    #[assoc(class = UpgradeClass::DragonEgg(DragonEgg))]
+    #[name(base = "A Crumbly Egg")]
+    ACrumblyEgg,
 }
 
 impl Upgrade {
@@ -906,6 +1068,15 @@ impl Upgrade {
         self.class().cost()
     }
 
+    pub(crate) fn resolved_cost(&self, state: &State, cps: &Cps) -> CostResolved {
+        match self.cost().resolve(state, cps) {
+            CostResolved::Cookies(cookies) => {
+                CostResolved::Cookies(cookies * state.buffs.upgrade_cost_mult())
+            }
+            cost => cost,
+        }
+    }
+
     pub(crate) fn buy(&self, state: &mut State, changeset: &mut Changeset) {
         self.class().buy(state, changeset);
         changeset.available_upgrades = true;
@@ -916,7 +1087,36 @@ impl Upgrade {
     }
 
     pub fn should_add_to_owned(&self) -> bool {
-        !matches!(self.class(), UpgradeClass::Switch(_))
+        !matches!(
+            self.class(),
+            UpgradeClass::Switch(_) | UpgradeClass::SeasonSwitcher(_)
+        )
+    }
+
+    pub(crate) fn season_drop(&self) -> Option<Season> {
+        match self.class() {
+            UpgradeClass::SeasonDrop(u) => Some(u.season()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn santa_gift_level(&self) -> Option<SantaLevel> {
+        match self.class() {
+            UpgradeClass::SantaGift(u) => Some(u.level()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn can_be_permanent(&self) -> bool {
+        matches!(
+            self.class(),
+            UpgradeClass::Tiered(_)
+                | UpgradeClass::ClickAndCursor(_)
+                | UpgradeClass::GrandmaJob(_)
+                | UpgradeClass::Kitten(_)
+                | UpgradeClass::Luck(_)
+                | UpgradeClass::SeasonDrop(_)
+        )
     }
 }
 
@@ -925,8 +1125,15 @@ enum UpgradeClass {
     ClickAndCursor(ClickAndCursor),
     GrandmaJob(GrandmaJob),
     Kitten(Kitten),
+    Luck(Luck),
     Research(Research),
+    WrinklerBoost(WrinklerBoost),
+    Synergy(Synergy),
     Switch(Switch),
+    SeasonSwitcher(SeasonSwitcher),
+    SeasonDrop(SeasonDrop),
+    SantaGift(SantaGift),
+    DragonEgg(DragonEgg),
 }
 
 impl UpgradeClass {
@@ -936,8 +1143,15 @@ impl UpgradeClass {
             Self::ClickAndCursor(u) => u.cost(),
             Self::GrandmaJob(u) => u.cost(),
             Self::Kitten(u) => u.cost(),
+            Self::Luck(u) => u.cost(),
             Self::Research(u) => u.cost(),
+            Self::WrinklerBoost(u) => u.cost(),
+            Self::Synergy(u) => u.cost(),
             Self::Switch(u) => u.cost(),
+            Self::SeasonSwitcher(u) => u.cost(),
+            Self::SeasonDrop(u) => u.cost(),
+            Self::SantaGift(u) => u.cost(),
+            Self::DragonEgg(u) => u.cost(),
         }
     }
 
@@ -947,8 +1161,15 @@ impl UpgradeClass {
             Self::ClickAndCursor(u) => u.req(),
             Self::GrandmaJob(u) => u.req(),
             Self::Kitten(u) => u.req(),
+            Self::Luck(u) => u.req(),
             Self::Research(u) => u.req(),
+            Self::WrinklerBoost(u) => u.req(),
+            Self::Synergy(u) => u.req(),
             Self::Switch(u) => u.req(),
+            Self::SeasonSwitcher(u) => u.req(),
+            Self::SeasonDrop(u) => u.req(),
+            Self::SantaGift(u) => u.req(),
+            Self::DragonEgg(u) => u.req(),
         }
     }
 
@@ -958,8 +1179,15 @@ impl UpgradeClass {
             Self::ClickAndCursor(u) => u.buy(state, changeset),
             Self::GrandmaJob(u) => u.buy(state, changeset),
             Self::Kitten(u) => u.buy(state, changeset),
+            Self::Luck(u) => u.buy(state, changeset),
             Self::Research(u) => u.buy(state, changeset),
+            Self::WrinklerBoost(u) => u.buy(state, changeset),
+            Self::Synergy(u) => u.buy(state, changeset),
             Self::Switch(u) => u.buy(state, changeset),
+            Self::SeasonSwitcher(u) => u.buy(state, changeset),
+            Self::SeasonDrop(u) => u.buy(state, changeset),
+            Self::SantaGift(u) => u.buy(state, changeset),
+            Self::DragonEgg(u) => u.buy(state, changeset),
         }
     }
 
@@ -969,8 +1197,15 @@ impl UpgradeClass {
             Self::ClickAndCursor(u) => u.effect_info(),
             Self::GrandmaJob(u) => u.effect_info(),
             Self::Kitten(u) => u.effect_info(),
+            Self::Luck(u) => u.effect_info(),
             Self::Research(u) => u.effect_info(),
+            Self::WrinklerBoost(u) => u.effect_info(),
+            Self::Synergy(u) => u.effect_info(),
             Self::Switch(u) => u.effect_info(),
+            Self::SeasonSwitcher(u) => u.effect_info(),
+            Self::SeasonDrop(u) => u.effect_info(),
+            Self::SantaGift(u) => u.effect_info(),
+            Self::DragonEgg(u) => u.effect_info(),
         }
     }
 }
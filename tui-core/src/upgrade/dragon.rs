@@ -0,0 +1,29 @@
+use super::effect_info::UpgradeEffectInfo;
+use crate::{Changeset, Cost, HeavenlyUpgrade, State, req::Req};
+
+const DRAGON_EGG_COST: f64 = 25.0;
+
+pub struct DragonEgg;
+
+impl DragonEgg {
+    pub fn cost(&self) -> Cost {
+        Cost::Cookies(DRAGON_EGG_COST)
+    }
+
+    pub fn req(&self) -> Req {
+        Req::Custom(|state| {
+            state
+                .heavenly_upgrades
+                .has(HeavenlyUpgrade::HowToBakeYourDragon)
+                && !state.dragon.laid()
+        })
+    }
+
+    pub fn buy(&self, state: &mut State, _changeset: &mut Changeset) {
+        state.dragon.lay_egg();
+    }
+
+    pub fn effect_info(&self) -> UpgradeEffectInfo {
+        UpgradeEffectInfo::DragonEgg
+    }
+}
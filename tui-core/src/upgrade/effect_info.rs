@@ -1,4 +1,4 @@
-use crate::Building;
+use crate::{Building, Season};
 
 pub enum UpgradeEffectInfo {
     Tiered(Building),
@@ -9,14 +9,31 @@ pub enum UpgradeEffectInfo {
         num_req_for_1p: u16,
     },
     Kitten,
+    Synergy {
+        lower: Building,
+        higher: Building,
+        lower_mult_per: f64,
+        higher_mult_per: f64,
+    },
+    GoldenCookieFrequency(f64),
+    GoldenCookieEffectDuration(f64),
     Research {
         effect: UpgradeInfoEffectResearch,
         warning: Option<UpgradeInfoEffectResearchWarning>,
     },
+    WrinklerMaxSize(usize),
+    WrinklerPopMult(f64),
     ElderPledge,
     ElderCovenant {
         revoke: bool,
     },
+    SeasonSwitcher(Season),
+    SeasonDrop {
+        season: Season,
+        mult: f64,
+    },
+    SantaGift(UpgradeInfoEffectSantaGift),
+    DragonEgg,
 }
 
 pub enum UpgradeInfoEffectResearch {
@@ -27,6 +44,12 @@ pub enum UpgradeInfoEffectResearch {
     ElderPledgesLastTwiceAsLong,
 }
 
+pub enum UpgradeInfoEffectSantaGift {
+    CpsMult(f64),
+    CpsMultPerSantaLevel(f64),
+    ReindeerSpawnMult(f64),
+}
+
 pub enum UpgradeInfoEffectResearchWarning {
     One,
     Two,
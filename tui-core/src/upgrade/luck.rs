@@ -0,0 +1,66 @@
+use super::effect_info::UpgradeEffectInfo;
+use crate::{
+    Changeset, Cost, State,
+    req::{Cmp, Req},
+};
+
+const FREQUENCY_MULT: f64 = 2.0;
+const EFFECT_DURATION_MULT: f64 = 2.0;
+
+pub struct Luck {
+    clicks_req: usize,
+    mode: LuckMode,
+    cost: f64,
+}
+
+impl Luck {
+    pub const fn new(clicks_req: usize, mode: LuckMode, cost: f64) -> Self {
+        Self {
+            clicks_req,
+            mode,
+            cost,
+        }
+    }
+
+    pub fn cost(&self) -> Cost {
+        Cost::Cookies(self.cost)
+    }
+
+    pub fn req(&self) -> Req {
+        Req::GoldenCookieClicked(Cmp::AboveOrEq(self.clicks_req))
+    }
+
+    pub fn buy(&self, state: &mut State, _changeset: &mut Changeset) {
+        match self.mode {
+            LuckMode::Frequency => {
+                state.golden_cookies.modify_spawning(|tmin, tmax| {
+                    *tmin /= FREQUENCY_MULT;
+                    *tmax /= FREQUENCY_MULT;
+                });
+                state
+                    .golden_cookies
+                    .modify_duration(|secs| *secs *= FREQUENCY_MULT);
+            }
+            LuckMode::EffectDuration => {
+                state
+                    .golden_cookies
+                    .add_effect_duration_mult(EFFECT_DURATION_MULT);
+            }
+        }
+    }
+
+    pub fn effect_info(&self) -> UpgradeEffectInfo {
+        match self.mode {
+            LuckMode::Frequency => UpgradeEffectInfo::GoldenCookieFrequency(FREQUENCY_MULT),
+            LuckMode::EffectDuration => {
+                UpgradeEffectInfo::GoldenCookieEffectDuration(EFFECT_DURATION_MULT)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum LuckMode {
+    Frequency,
+    EffectDuration,
+}
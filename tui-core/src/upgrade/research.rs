@@ -3,6 +3,7 @@ use super::effect_info::{
 };
 use crate::{
     Achievement, Building, Changeset, Cost, GrandmapocalypsePhase, State,
+    modifiers::Modifier,
     req::{Cmp, Req},
 };
 use cookie_clicker_tui_utils::num;
@@ -61,10 +62,10 @@ impl Research {
                     .set_grandma_has_bingo_center(true, changeset);
             }
             Self::SpecializedChocolateChips => {
-                state.grandmapocalypse.add_cps_mult(1.01, changeset);
+                state.modifiers.add(Modifier::CpsMult(1.01), changeset);
             }
             Self::DesignerCocoaBeans => {
-                state.grandmapocalypse.add_cps_mult(1.02, changeset);
+                state.modifiers.add(Modifier::CpsMult(1.02), changeset);
             }
             Self::RitualRollingPins => {
                 state
@@ -72,7 +73,7 @@ impl Research {
                     .set_grandma_has_ritual_rolling_pins(true, changeset);
             }
             Self::UnderworldOvens => {
-                state.grandmapocalypse.add_cps_mult(1.03, changeset);
+                state.modifiers.add(Modifier::CpsMult(1.03), changeset);
             }
             Self::OneMind => {
                 state.buildings.set_grandma_has_one_mind(true, changeset);
@@ -81,7 +82,7 @@ impl Research {
                     .set_phase(GrandmapocalypsePhase::Awoken);
             }
             Self::ExoticNuts => {
-                state.grandmapocalypse.add_cps_mult(1.04, changeset);
+                state.modifiers.add(Modifier::CpsMult(1.04), changeset);
             }
             Self::CommunalBrainsweep => {
                 state
@@ -92,7 +93,7 @@ impl Research {
                     .set_phase(GrandmapocalypsePhase::Displeased);
             }
             Self::ArcaneSugar => {
-                state.grandmapocalypse.add_cps_mult(1.05, changeset);
+                state.modifiers.add(Modifier::CpsMult(1.05), changeset);
             }
             Self::ElderPact => {
                 state.buildings.set_grandma_has_elder_pact(true, changeset);
@@ -0,0 +1,87 @@
+use super::{
+    Upgrade,
+    effect_info::{UpgradeEffectInfo, UpgradeInfoEffectSantaGift},
+};
+use crate::{
+    Changeset, Cost, State,
+    modifiers::Modifier,
+    req::Req,
+    santa::{self, SantaLevel},
+};
+use cookie_clicker_tui_utils::num;
+
+pub const SANTA_GIFT_COST: Cost = Cost::Cookies(2525.0);
+pub const SANTAS_DOMINION_COST: Cost = Cost::Cookies(2525.0 * num::QUINTILLION);
+
+const REINDEER_BAKING_GROUNDS_MULT: f64 = 0.5;
+
+pub struct SantaGift {
+    upgrade: Upgrade,
+    level: SantaLevel,
+    effect: SantaGiftEffect,
+    cost: Cost,
+}
+
+impl SantaGift {
+    pub const fn new(
+        upgrade: Upgrade,
+        level: SantaLevel,
+        effect: SantaGiftEffect,
+        cost: Cost,
+    ) -> Self {
+        Self {
+            upgrade,
+            level,
+            effect,
+            cost,
+        }
+    }
+
+    pub fn level(&self) -> SantaLevel {
+        self.level
+    }
+
+    pub fn cost(&self) -> Cost {
+        self.cost
+    }
+
+    pub fn req(&self) -> Req {
+        let upgrade = self.upgrade;
+
+        Req::CustomBox(Box::new(move |state| state.santa.gifted(upgrade)))
+    }
+
+    pub fn buy(&self, state: &mut State, changeset: &mut Changeset) {
+        match self.effect {
+            SantaGiftEffect::CpsMult(mult) => {
+                state.modifiers.add(Modifier::CpsMult(mult), changeset)
+            }
+            SantaGiftEffect::Legacy => state.santa.enable_legacy(changeset),
+            SantaGiftEffect::ReindeerFrequency => {
+                state.seasons.modify_reindeer_spawning(|tmin, tmax| {
+                    *tmin *= REINDEER_BAKING_GROUNDS_MULT;
+                    *tmax *= REINDEER_BAKING_GROUNDS_MULT;
+                })
+            }
+        }
+    }
+
+    pub fn effect_info(&self) -> UpgradeEffectInfo {
+        UpgradeEffectInfo::SantaGift(match self.effect {
+            SantaGiftEffect::CpsMult(mult) => UpgradeInfoEffectSantaGift::CpsMult(mult),
+            SantaGiftEffect::Legacy => {
+                UpgradeInfoEffectSantaGift::CpsMultPerSantaLevel(santa::LEGACY_CPS_MULT_PER_LEVEL)
+            }
+            SantaGiftEffect::ReindeerFrequency => {
+                UpgradeInfoEffectSantaGift::ReindeerSpawnMult(REINDEER_BAKING_GROUNDS_MULT)
+            }
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum SantaGiftEffect {
+    CpsMult(f64),
+    Legacy,
+    ReindeerFrequency,
+}
@@ -0,0 +1,101 @@
+use super::{Upgrade, effect_info::UpgradeEffectInfo};
+use crate::{
+    Changeset, Cost, CostDyn, CostResolved, HeavenlyUpgrade, State, calc,
+    cps::Cps,
+    modifiers::Modifier,
+    req::Req,
+    season::{self, Season},
+};
+
+// eggs get pricier the more of them you own
+pub const EASTER_EGG_COST: Cost = Cost::Dyn(CostDyn::new(easter_egg_cost));
+
+fn easter_egg_cost(state: &State, _: &Cps) -> CostResolved {
+    let eggs_owned = state
+        .owned_upgrades
+        .as_set()
+        .iter()
+        .filter(|u| u.season_drop() == Some(Season::Easter))
+        .count();
+
+    CostResolved::Cookies(calc::easter_egg_cost(eggs_owned))
+}
+
+pub struct SeasonSwitcher {
+    season: Season,
+}
+
+impl SeasonSwitcher {
+    pub const fn new(season: Season) -> Self {
+        Self { season }
+    }
+
+    pub fn cost(&self) -> Cost {
+        Cost::Dyn(CostDyn::new(|state, cps| {
+            CostResolved::Cookies(calc::season_switcher_cost(
+                cps.total,
+                state.seasons.switches(),
+            ))
+        }))
+    }
+
+    pub fn req(&self) -> Req {
+        let season = self.season;
+
+        Req::CustomBox(Box::new(move |state| {
+            state.heavenly_upgrades.has(HeavenlyUpgrade::SeasonSwitcher)
+                && !state.seasons.is(season)
+        }))
+    }
+
+    pub fn buy(&self, state: &mut State, changeset: &mut Changeset) {
+        season::switch(state, self.season, changeset);
+    }
+
+    pub fn effect_info(&self) -> UpgradeEffectInfo {
+        UpgradeEffectInfo::SeasonSwitcher(self.season)
+    }
+}
+
+pub struct SeasonDrop {
+    upgrade: Upgrade,
+    season: Season,
+    mult: f64,
+    cost: Cost,
+}
+
+impl SeasonDrop {
+    pub const fn new(upgrade: Upgrade, season: Season, mult: f64, cost: Cost) -> Self {
+        Self {
+            upgrade,
+            season,
+            mult,
+            cost,
+        }
+    }
+
+    pub fn season(&self) -> Season {
+        self.season
+    }
+
+    pub fn cost(&self) -> Cost {
+        self.cost
+    }
+
+    pub fn req(&self) -> Req {
+        let upgrade = self.upgrade;
+
+        Req::CustomBox(Box::new(move |state| state.seasons.dropped(upgrade)))
+    }
+
+    pub fn buy(&self, state: &mut State, changeset: &mut Changeset) {
+        state.modifiers.add(Modifier::CpsMult(self.mult), changeset);
+    }
+
+    pub fn effect_info(&self) -> UpgradeEffectInfo {
+        UpgradeEffectInfo::SeasonDrop {
+            season: self.season,
+            mult: self.mult,
+        }
+    }
+}
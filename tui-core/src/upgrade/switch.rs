@@ -43,14 +43,18 @@ impl Switch {
     pub fn buy(&self, state: &mut State, changeset: &mut Changeset) {
         match self {
             Self::ElderPledge => {
-                state
-                    .grandmapocalypse
-                    .appease_temporarily(&mut state.cookies, changeset);
+                state.grandmapocalypse.appease_temporarily(
+                    &mut state.cookies,
+                    &state.modifiers,
+                    changeset,
+                );
             }
             Self::ElderCovenant => {
-                state
-                    .grandmapocalypse
-                    .appease_permanently(&mut state.cookies, changeset);
+                state.grandmapocalypse.appease_permanently(
+                    &mut state.cookies,
+                    &state.modifiers,
+                    changeset,
+                );
             }
             Self::RevokeElderCovenant => {
                 state.grandmapocalypse.unappease(changeset);
@@ -0,0 +1,45 @@
+use super::effect_info::UpgradeEffectInfo;
+use crate::{
+    Building, Changeset, Cost, State, calc,
+    req::{Cmp, Req},
+};
+
+const COUNT_REQ: u16 = 15;
+const COST_MULT: f64 = 10.0;
+
+pub struct Synergy {
+    lower: Building,
+    higher: Building,
+}
+
+impl Synergy {
+    pub const fn new(lower: Building, higher: Building) -> Self {
+        Self { lower, higher }
+    }
+
+    pub fn cost(&self) -> Cost {
+        Cost::Cookies((self.lower.base_cost() * COST_MULT + self.higher.base_cost()) * COST_MULT)
+    }
+
+    pub fn req(&self) -> Req {
+        Req::AllBox(Box::new([
+            Req::BuildingCount(self.lower, Cmp::AboveOrEq(COUNT_REQ)),
+            Req::BuildingCount(self.higher, Cmp::AboveOrEq(COUNT_REQ)),
+        ]))
+    }
+
+    pub fn buy(&self, state: &mut State, changeset: &mut Changeset) {
+        state
+            .buildings
+            .add_synergy(self.lower, self.higher, changeset);
+    }
+
+    pub fn effect_info(&self) -> UpgradeEffectInfo {
+        UpgradeEffectInfo::Synergy {
+            lower: self.lower,
+            higher: self.higher,
+            lower_mult_per: calc::synergy_mult(1, true),
+            higher_mult_per: calc::synergy_mult(1, false),
+        }
+    }
+}
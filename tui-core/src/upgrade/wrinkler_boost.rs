@@ -0,0 +1,56 @@
+use super::effect_info::UpgradeEffectInfo;
+use crate::{
+    Changeset, Cost, State,
+    modifiers::Modifier,
+    req::{Cmp, Req},
+};
+
+pub struct WrinklerBoost {
+    popped_req: usize,
+    mode: WrinklerBoostMode,
+    cost: f64,
+}
+
+impl WrinklerBoost {
+    pub const fn new(popped_req: usize, mode: WrinklerBoostMode, cost: f64) -> Self {
+        Self {
+            popped_req,
+            mode,
+            cost,
+        }
+    }
+
+    pub fn cost(&self) -> Cost {
+        Cost::Cookies(self.cost)
+    }
+
+    pub fn req(&self) -> Req {
+        Req::WrinklersPopped(Cmp::AboveOrEq(self.popped_req))
+    }
+
+    pub fn buy(&self, state: &mut State, changeset: &mut Changeset) {
+        match self.mode {
+            WrinklerBoostMode::MaxSize(n) => {
+                state.grandmapocalypse.wrinklers_mut().add_max_size(n);
+            }
+            WrinklerBoostMode::PopMult(mult) => {
+                state
+                    .modifiers
+                    .add(Modifier::WrinklerPopMult(mult), changeset);
+            }
+        }
+    }
+
+    pub fn effect_info(&self) -> UpgradeEffectInfo {
+        match self.mode {
+            WrinklerBoostMode::MaxSize(n) => UpgradeEffectInfo::WrinklerMaxSize(n),
+            WrinklerBoostMode::PopMult(mult) => UpgradeEffectInfo::WrinklerPopMult(mult),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum WrinklerBoostMode {
+    MaxSize(usize),
+    PopMult(f64),
+}
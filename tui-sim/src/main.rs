@@ -0,0 +1,99 @@
+mod script;
+mod summary;
+
+use self::{script::Script, summary::Summary};
+use anyhow::{Context, Result, bail};
+use clap::Parser;
+use cookie_clicker_tui_core::{Action, Core};
+use cookie_clicker_tui_utils::frames::FPS;
+use serde::de::DeserializeOwned;
+use std::{fs, path::Path};
+
+/// Debug builds shorten many of the game's timers, so the sim only runs in release builds,
+/// e.g. `cargo run --release -p cookie-clicker-tui-sim`
+#[derive(Parser)]
+struct Cli {
+    /// The path to a script of actions to apply
+    script: Option<Box<Path>>,
+
+    /// The number of ticks to simulate
+    #[clap(long, default_value_t = (60.0 * 60.0 * FPS) as u64)]
+    ticks: u64,
+
+    /// Start from a save file instead of a new game
+    #[clap(long)]
+    save: Option<Box<Path>>,
+
+    /// Seed the new game's randomness, save files carry their own
+    #[clap(long, conflicts_with = "save")]
+    seed: Option<u64>,
+
+    /// Click every golden cookie as soon as it appears, which flags the game as cheated
+    #[clap(long)]
+    click_golden_cookies: bool,
+}
+
+// only the core is read from save files
+#[derive(serde::Deserialize)]
+struct SaveData {
+    core: Core,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    if cfg!(debug_assertions) {
+        bail!("debug builds use shortened timers, run the sim with `--release` instead");
+    }
+
+    let mut core = match (&cli.save, cli.seed) {
+        (Some(path), _) => read_json::<SaveData>(path)?.core,
+        (None, Some(seed)) => Core::with_seed(seed),
+        (None, None) => Core::new(),
+    };
+
+    let script = match &cli.script {
+        Some(path) => read_json::<Script>(path)?,
+        None => Script::default(),
+    };
+
+    if cli.click_golden_cookies {
+        core.mark_cheated();
+    }
+
+    let mut summary = Summary::default();
+
+    for tick in 0..cli.ticks {
+        for action in script.actions_at(tick) {
+            summary.record_action(core.apply_action(action));
+        }
+
+        if cli.click_golden_cookies {
+            let chs = core
+                .golden_cookies()
+                .iter()
+                .map(|g| g.ch())
+                .collect::<Vec<_>>();
+
+            for ch in chs {
+                summary.record_action(core.apply_action(Action::ClickGoldenCookie { ch }));
+            }
+        }
+
+        let _ = core.tick();
+    }
+
+    summary.finish(&core, cli.ticks);
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&summary).context("could not serialize summary")?
+    );
+
+    Ok(())
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let text = fs::read_to_string(path).with_context(|| format!("failed to read '{path:?}'"))?;
+    serde_json::from_str(&text).with_context(|| format!("failed to parse '{path:?}'"))
+}
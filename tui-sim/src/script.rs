@@ -0,0 +1,39 @@
+use cookie_clicker_tui_core::Action;
+use serde::Deserialize;
+
+// a list of actions, e.g.
+// [
+//   { "tick": 0, "every": 3, "action": "ClickCookie" },
+//   { "tick": 900, "action": "BuyBuilding", "building": "Cursor", "count": 10 }
+// ]
+#[derive(Deserialize, Default)]
+#[serde(transparent)]
+pub struct Script(Vec<Step>);
+
+impl Script {
+    pub fn actions_at(&self, tick: u64) -> impl Iterator<Item = Action> {
+        self.0
+            .iter()
+            .filter(move |step| step.fires_at(tick))
+            .map(|step| step.action)
+    }
+}
+
+#[derive(Deserialize)]
+struct Step {
+    tick: u64,
+    // repeats the action every this many ticks after the first one
+    #[serde(default)]
+    every: Option<u64>,
+    #[serde(flatten)]
+    action: Action,
+}
+
+impl Step {
+    fn fires_at(&self, tick: u64) -> bool {
+        match self.every {
+            Some(every) => tick >= self.tick && (tick - self.tick).is_multiple_of(every),
+            None => tick == self.tick,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use cookie_clicker_tui_core::{Achievement, Building, Core, Upgrade};
+use cookie_clicker_tui_utils::frames::FPS;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Default)]
+pub struct Summary {
+    ticks: u64,
+    secs: f64,
+    cookies: f64,
+    cookies_all_time: f64,
+    cookies_all_time_from_clicking: f64,
+    cps: f64,
+    buildings: BTreeMap<Building, u16>,
+    upgrades: Vec<Upgrade>,
+    achievements: Vec<Achievement>,
+    golden_cookies_clicked: usize,
+    actions_applied: usize,
+    actions_failed: usize,
+}
+
+impl Summary {
+    pub fn record_action(&mut self, applied: bool) {
+        if applied {
+            self.actions_applied += 1;
+        } else {
+            self.actions_failed += 1;
+        }
+    }
+
+    pub fn finish(&mut self, core: &Core, ticks: u64) {
+        self.ticks = ticks;
+        self.secs = ticks as f64 / FPS;
+        self.cookies = core.cookies();
+        self.cookies_all_time = core.cookies_all_time();
+        self.cookies_all_time_from_clicking = core.cookies_all_time_from_clicking();
+        self.cps = core.cps();
+        self.buildings = core
+            .building_infos()
+            .map(|info| (info.building(), info.count()))
+            .collect();
+        self.upgrades = core.owned_upgrades().iter().copied().collect();
+        self.achievements = core.owned_achievements().iter().copied().collect();
+        self.golden_cookies_clicked = core.golden_cookies().click_count();
+    }
+}
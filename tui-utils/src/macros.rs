@@ -12,7 +12,7 @@ macro_rules! enum_map {
         }
 
         impl<T> $ident<T> {
-            $vis fn new(f: impl Fn($enum) -> T) -> Self {
+            $vis fn new(mut f: impl FnMut($enum) -> T) -> Self {
                 Self {
                     $($field: f($enum::$variant)),*
                 }
@@ -2,7 +2,7 @@ use crate::frames::FPS;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Refresh {
     cur: f64,
     max: f64,
@@ -59,6 +59,17 @@ impl Refresh {
         }
     }
 
+    // stops one frame short, so the next `finish` call is what completes it
+    pub fn fast_forward_secs(&mut self, secs: f64) {
+        self.cur = (self.cur + secs * FPS).min(self.max - 1.0).max(self.cur);
+    }
+
+    pub fn fast_forward_secs_cyclic(&mut self, secs: f64) -> u64 {
+        let next = self.cur + secs * FPS;
+        self.cur = next % self.max;
+        (next / self.max) as u64
+    }
+
     pub fn modify(&mut self, f: impl FnOnce(&mut f64)) {
         f(&mut self.max);
     }
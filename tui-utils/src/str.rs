@@ -20,3 +20,36 @@ pub fn indefinite_article(s: &str) -> impl fmt::Display {
     };
     IndefiniteArticle { article, s }
 }
+
+pub fn duration(secs: f64) -> impl fmt::Display {
+    struct Duration(u64);
+    impl fmt::Display for Duration {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            const UNITS: [(u64, &str, &str); 4] = [
+                (24 * 60 * 60, "day", "days"),
+                (60 * 60, "hour", "hours"),
+                (60, "minute", "minutes"),
+                (1, "second", "seconds"),
+            ];
+            let mut rest = self.0;
+            let mut parts = 0;
+            for (unit_secs, singular, plural) in UNITS {
+                let n = rest / unit_secs;
+                if n == 0 || parts == 2 {
+                    continue;
+                }
+                if parts > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{n} {}", pluralized(n as usize, singular, plural))?;
+                rest -= n * unit_secs;
+                parts += 1;
+            }
+            if parts == 0 {
+                write!(f, "0 seconds")?;
+            }
+            Ok(())
+        }
+    }
+    Duration(secs as u64)
+}
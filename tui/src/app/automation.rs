@@ -0,0 +1,98 @@
+use cookie_clicker_tui_core::{Action, Building, Core, CostResolved, Purchase};
+use cookie_clicker_tui_utils::frames::FPS;
+
+// every click is its own recorded action, so the rate is kept to something a replay can hold
+const MAX_CLICKS_PER_SEC: f64 = 100.0;
+
+#[derive(clap::ValueEnum, Debug, Copy, Clone)]
+pub enum AutobuyPolicy {
+    Cheapest,
+    BestPayback,
+}
+
+#[derive(Default, Debug)]
+pub struct AppAutomationState {
+    clicks_per_sec: f64,
+    golden_cookies: bool,
+    autobuy: Option<AutobuyPolicy>,
+    pending_clicks: f64,
+}
+
+impl AppAutomationState {
+    pub fn new(clicks_per_sec: f64, golden_cookies: bool, autobuy: Option<AutobuyPolicy>) -> Self {
+        Self {
+            clicks_per_sec: clicks_per_sec.clamp(0.0, MAX_CLICKS_PER_SEC),
+            golden_cookies,
+            autobuy,
+            pending_clicks: 0.0,
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.clicks_per_sec > 0.0 || self.golden_cookies || self.autobuy.is_some()
+    }
+
+    // the actions to take this tick, before the core ticks
+    pub(super) fn tick(&mut self, core: &Core) -> Vec<Action> {
+        let mut actions = Vec::new();
+
+        self.pending_clicks += self.clicks_per_sec / FPS;
+        while self.pending_clicks >= 1.0 {
+            self.pending_clicks -= 1.0;
+            actions.push(Action::ClickCookie);
+        }
+
+        if self.golden_cookies {
+            actions.extend(
+                core.golden_cookies()
+                    .iter()
+                    .map(|g| Action::ClickGoldenCookie { ch: g.ch() }),
+            );
+        }
+
+        let purchase = match self.autobuy {
+            Some(AutobuyPolicy::Cheapest) => cheapest(core),
+            Some(AutobuyPolicy::BestPayback) => core.best_buy(),
+            None => None,
+        };
+
+        match purchase {
+            Some(Purchase::Building(building))
+                if core.affordable(core.building_info(building).cost()) =>
+            {
+                actions.push(Action::BuyBuilding { building, count: 1 });
+            }
+            Some(Purchase::Upgrade(upgrade))
+                if core.affordable_resolved(core.upgrade_cost(upgrade)) =>
+            {
+                actions.push(Action::BuyUpgrade { upgrade });
+            }
+            _ => {}
+        }
+
+        actions
+    }
+}
+
+// switches are left alone, buying them would just flip them back and forth
+fn cheapest(core: &Core) -> Option<Purchase> {
+    let buildings = Building::VARIANTS.iter().map(|&building| {
+        let cost = core.resolve_cost(core.building_info(building).cost());
+        (Purchase::Building(building), cost)
+    });
+
+    let upgrades = core
+        .available_upgrades()
+        .iter()
+        .filter(|upgrade| upgrade.should_add_to_owned())
+        .map(|upgrade| (Purchase::Upgrade(*upgrade), core.upgrade_cost(*upgrade)));
+
+    buildings
+        .chain(upgrades)
+        .filter_map(|(purchase, cost)| match cost {
+            CostResolved::Cookies(c) => Some((purchase, c)),
+            _ => None,
+        })
+        .min_by(|(_, a), (_, b)| f64::total_cmp(a, b))
+        .map(|(purchase, _)| purchase)
+}
@@ -25,6 +25,7 @@ pub enum AppDebugView {
     Research,
     Grandmapocalypse,
     GoldenCookies,
+    Buffs,
     News,
     List,
     Interface,
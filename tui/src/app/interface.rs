@@ -1,4 +1,7 @@
-use cookie_clicker_tui_core::{Building, Upgrade};
+use cookie_clicker_tui_core::{
+    Building, BuildingBulk, DragonAura, Good, HeavenlyUpgrade, SantaLevel, Seed, Soil, Spell,
+    Upgrade,
+};
 use cookie_clicker_tui_utils::refresh::{Refresh, RefreshOptionExt};
 use enum_assoc::Assoc;
 use ratatui::style::{Style, Stylize};
@@ -7,6 +10,7 @@ use std::collections::{HashSet, VecDeque};
 #[derive(Default, Debug)]
 pub struct AppInterfaceState {
     sell_mode: bool,
+    bulk: BuildingBulk,
     pressed_cookie: Option<Refresh>,
     flashes: AppFlashes,
 }
@@ -16,6 +20,10 @@ impl AppInterfaceState {
         self.sell_mode
     }
 
+    pub fn bulk(&self) -> BuildingBulk {
+        self.bulk
+    }
+
     pub fn pressed_cookie(&self) -> bool {
         self.pressed_cookie.is_some()
     }
@@ -28,6 +36,10 @@ impl AppInterfaceState {
         self.sell_mode = !self.sell_mode;
     }
 
+    pub(super) fn cycle_bulk(&mut self) {
+        self.bulk = self.bulk.next();
+    }
+
     pub(super) fn set_pressed_cookie(&mut self) {
         self.pressed_cookie = Some(Refresh::new_frames(3.0));
     }
@@ -94,12 +106,50 @@ pub enum AppFlash {
     SugarLumpsUnlocked,
     #[assoc(class = AppFlashClass::Info)]
     ResearchCompleted,
+    #[assoc(class = AppFlashClass::Info)]
+    Ascended,
+    #[assoc(class = AppFlashClass::Info)]
+    SugarLumpHarvested(u16),
+    #[assoc(class = AppFlashClass::Warning)]
+    SugarLumpBotched,
     #[assoc(class = AppFlashClass::Error)]
     CantAffordBuilding(Building),
     #[assoc(class = AppFlashClass::Error)]
     CantAffordUpgrade(Upgrade),
     #[assoc(class = AppFlashClass::Error)]
+    CantAffordBuildingLevel(Building),
+    #[assoc(class = AppFlashClass::Error)]
     CantSellUnownedBuilding(Building),
+    #[assoc(class = AppFlashClass::Error)]
+    CantAffordHeavenlyUpgrade(HeavenlyUpgrade),
+    #[assoc(class = AppFlashClass::Warning)]
+    HeavenlyUpgradeAlreadyOwned(HeavenlyUpgrade),
+    #[assoc(class = AppFlashClass::Error)]
+    HeavenlyUpgradeLocked(HeavenlyUpgrade),
+    #[assoc(class = AppFlashClass::Error)]
+    CantPlantSeed(Seed),
+    #[assoc(class = AppFlashClass::Error)]
+    CantUseSoil(Soil),
+    #[assoc(class = AppFlashClass::Info)]
+    SpellCast(Spell),
+    #[assoc(class = AppFlashClass::Warning)]
+    SpellBackfired(Spell),
+    #[assoc(class = AppFlashClass::Error)]
+    NotEnoughMagic(Spell),
+    #[assoc(class = AppFlashClass::Error)]
+    NoWorshipSwaps,
+    #[assoc(class = AppFlashClass::Info)]
+    SantaLeveledUp(SantaLevel),
+    #[assoc(class = AppFlashClass::Error)]
+    CantLevelUpSanta,
+    #[assoc(class = AppFlashClass::Error)]
+    CantTrainDragon,
+    #[assoc(class = AppFlashClass::Error)]
+    CantSetDragonAura(DragonAura),
+    #[assoc(class = AppFlashClass::Error)]
+    CantBuyStock(Good),
+    #[assoc(class = AppFlashClass::Error)]
+    CantSellStock(Good),
 }
 
 impl AppFlash {
@@ -1,3 +1,4 @@
+mod automation;
 mod bakery;
 mod changeset;
 mod debug;
@@ -8,6 +9,7 @@ mod news;
 mod tick;
 
 pub use self::{
+    automation::{AppAutomationState, AutobuyPolicy},
     bakery::AppBakery,
     changeset::AppChangesetState,
     debug::{AppDebugState, AppDebugView},
@@ -21,17 +23,23 @@ pub use self::{
 use crate::{
     audio::Audio,
     event::{Event, Events},
+    replay::Replay,
     save::Save,
 };
 use anyhow::{Context, Result};
-use cookie_clicker_tui_core::Core;
+use cookie_clicker_tui_core::{
+    Action, Core, DragonAura, DragonAuraSlot, Good, HeavenlyUpgrade, OfflineProgress, Seed, Soil,
+    Spell, SpellOutcome, Spirit, SpiritSlot,
+};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::DefaultTerminal;
 
 pub struct App {
     save: Save,
+    replay: Replay,
     core: Core,
     audio: Audio,
+    automation: AppAutomationState,
     tick: AppTickState,
     list: AppListState,
     modal: AppModalState,
@@ -45,15 +53,29 @@ pub struct App {
 }
 
 impl App {
-    pub fn new(save: Save, core: Core, name: Option<Box<str>>, audio: Audio) -> Self {
+    pub fn new(
+        save: Save,
+        replay: Replay,
+        core: Core,
+        name: Option<Box<str>>,
+        audio: Audio,
+        automation: AppAutomationState,
+        offline: Option<OfflineProgress>,
+    ) -> Self {
         let news = AppNewsState::new(&core);
+        let modal = match offline {
+            Some(progress) => AppModalState::Offline(progress),
+            None => AppModalState::default(),
+        };
         Self {
             save,
+            replay,
             core,
             audio,
+            automation,
             tick: AppTickState::default(),
             list: AppListState::default(),
-            modal: AppModalState::default(),
+            modal,
             iface: AppInterfaceState::default(),
             changeset: AppChangesetState::default(),
             debug: AppDebugState::default(),
@@ -82,6 +104,27 @@ impl App {
                         AppModalState::Wrinklers { .. } => {
                             self.handle_wrinklers_key_event(event);
                         }
+                        AppModalState::Ascending => {
+                            self.handle_ascending_key_event(event);
+                        }
+                        AppModalState::HeavenlyUpgrades { .. } => {
+                            self.handle_heavenly_upgrades_key_event(event);
+                        }
+                        AppModalState::StockMarket { .. } => {
+                            self.handle_stock_market_key_event(event);
+                        }
+                        AppModalState::Grimoire => {
+                            self.handle_grimoire_key_event(event);
+                        }
+                        AppModalState::Pantheon { .. } => {
+                            self.handle_pantheon_key_event(event);
+                        }
+                        AppModalState::Garden { .. } => {
+                            self.handle_garden_key_event(event);
+                        }
+                        AppModalState::Dragon { .. } => {
+                            self.handle_dragon_key_event(event);
+                        }
                         _ => {
                             self.handle_key_event(event).await?;
                         }
@@ -102,6 +145,7 @@ impl App {
 
         match event.code {
             KeyCode::Enter => {
+                self.replay.record_rename(self.tick.tick_no(), name);
                 self.bakery.set_name(&**name);
                 self.modal.close();
             }
@@ -136,16 +180,280 @@ impl App {
             }
             KeyCode::Char('p') => {
                 if let Some(selected) = state.selected {
-                    self.core.pop_wrinkler(selected);
+                    self.act(Action::PopWrinkler { index: selected });
                 }
                 if self.core.grandmapocalypse().wrinklers().is_empty() {
                     self.modal.close();
                 }
             }
             KeyCode::Char('a') => {
-                self.core.pop_all_wrinklers();
+                self.act(Action::PopAllWrinklers);
+                self.modal.close();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_ascending_key_event(&mut self, event: KeyEvent) {
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('a') => {
+                self.modal.close();
+            }
+            KeyCode::Enter => {
+                if self.act(Action::Ascend) {
+                    self.iface.add_flash(AppFlash::Ascended);
+                }
+                self.modal.close();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_heavenly_upgrades_key_event(&mut self, event: KeyEvent) {
+        let state = match &mut self.modal {
+            AppModalState::HeavenlyUpgrades { state } => state,
+            _ => unreachable!(),
+        };
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('h') => {
+                self.modal.close();
+            }
+            KeyCode::Up => {
+                state.previous();
+            }
+            KeyCode::Down => {
+                state.next();
+            }
+            KeyCode::Enter => {
+                let Some(upgrade) = state
+                    .selected
+                    .and_then(|i| HeavenlyUpgrade::VARIANTS.get(i).copied())
+                else {
+                    return;
+                };
+
+                if self.core.owned_heavenly_upgrades().contains(&upgrade) {
+                    self.iface
+                        .add_flash(AppFlash::HeavenlyUpgradeAlreadyOwned(upgrade));
+                } else if !self.core.heavenly_upgrade_unlocked(upgrade) {
+                    self.iface
+                        .add_flash(AppFlash::HeavenlyUpgradeLocked(upgrade));
+                } else if !self.act(Action::BuyHeavenlyUpgrade { upgrade }) {
+                    self.iface
+                        .add_flash(AppFlash::CantAffordHeavenlyUpgrade(upgrade));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_stock_market_key_event(&mut self, event: KeyEvent) {
+        let state = match &mut self.modal {
+            AppModalState::StockMarket { state } => state,
+            _ => unreachable!(),
+        };
+
+        let good = state.selected.and_then(|i| Good::VARIANTS.get(i).copied());
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('m') => {
+                self.modal.close();
+            }
+            KeyCode::Up => {
+                state.previous();
+            }
+            KeyCode::Down => {
+                state.next();
+            }
+            KeyCode::Char('b') => {
+                if let Some(good) = good
+                    && !self.act(Action::BuyStock { good, count: 1 })
+                {
+                    self.iface.add_flash(AppFlash::CantBuyStock(good));
+                }
+            }
+            KeyCode::Char('s') => {
+                if let Some(good) = good
+                    && !self.act(Action::SellStock { good, count: 1 })
+                {
+                    self.iface.add_flash(AppFlash::CantSellStock(good));
+                }
+            }
+            KeyCode::Char('S') => {
+                if let Some(good) = good {
+                    let owned = self.core.stock_market().good(good).owned();
+                    if !self.act(Action::SellStock { good, count: owned }) {
+                        self.iface.add_flash(AppFlash::CantSellStock(good));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_grimoire_key_event(&mut self, event: KeyEvent) {
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('c') => {
+                self.modal.close();
+            }
+            KeyCode::Char(ch @ '1'..='9') => {
+                let Some(spell) = Spell::VARIANTS.get(ch as usize - '1' as usize).copied() else {
+                    return;
+                };
+                let outcome =
+                    self.act_with(Action::CastSpell { spell }, |core| core.cast_spell(spell));
+                let flash = match outcome {
+                    Some(SpellOutcome::Success) => AppFlash::SpellCast(spell),
+                    Some(SpellOutcome::Backfire) => AppFlash::SpellBackfired(spell),
+                    None => AppFlash::NotEnoughMagic(spell),
+                };
+                self.iface.add_flash(flash);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_pantheon_key_event(&mut self, event: KeyEvent) {
+        let state = match &mut self.modal {
+            AppModalState::Pantheon { state } => state,
+            _ => unreachable!(),
+        };
+
+        let spirit = state
+            .selected
+            .and_then(|i| Spirit::VARIANTS.get(i).copied());
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('t') => {
+                self.modal.close();
+            }
+            KeyCode::Up => {
+                state.previous();
+            }
+            KeyCode::Down => {
+                state.next();
+            }
+            KeyCode::Char(ch @ '1'..='3') => {
+                let slot = SpiritSlot::VARIANTS[ch as usize - '1' as usize];
+                if let Some(spirit) = spirit
+                    && self.core.pantheon().spirit(slot) != Some(spirit)
+                    && !self.act(Action::SlotSpirit {
+                        slot,
+                        spirit: Some(spirit),
+                    })
+                {
+                    self.iface.add_flash(AppFlash::NoWorshipSwaps);
+                }
+            }
+            KeyCode::Char('u') => {
+                if let Some(slot) = spirit.and_then(|s| self.core.pantheon().slot_of(s)) {
+                    self.act(Action::SlotSpirit { slot, spirit: None });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_dragon_key_event(&mut self, event: KeyEvent) {
+        let state = match &mut self.modal {
+            AppModalState::Dragon { state } => state,
+            _ => unreachable!(),
+        };
+
+        let aura = state
+            .selected
+            .and_then(|i| DragonAura::VARIANTS.get(i).copied());
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('k') => {
                 self.modal.close();
             }
+            KeyCode::Up => {
+                state.previous();
+            }
+            KeyCode::Down => {
+                state.next();
+            }
+            KeyCode::Char('t') => {
+                if !self.act(Action::TrainDragon) {
+                    self.iface.add_flash(AppFlash::CantTrainDragon);
+                }
+            }
+            KeyCode::Char(ch @ '1'..='2') => {
+                let slot = DragonAuraSlot::VARIANTS[ch as usize - '1' as usize];
+                if let Some(aura) = aura
+                    && self.core.dragon().aura(slot) != Some(aura)
+                    && !self.act(Action::SetDragonAura {
+                        slot,
+                        aura: Some(aura),
+                    })
+                {
+                    self.iface.add_flash(AppFlash::CantSetDragonAura(aura));
+                }
+            }
+            KeyCode::Char('u') => {
+                if let Some(slot) = aura.and_then(|a| self.core.dragon().slot_of(a)) {
+                    self.act(Action::SetDragonAura { slot, aura: None });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_garden_key_event(&mut self, event: KeyEvent) {
+        let (x, y, seed) = match &mut self.modal {
+            AppModalState::Garden { x, y, seed } => (x, y, seed),
+            _ => unreachable!(),
+        };
+        let (width, height) = self.core.garden_plot_size();
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('f') => {
+                self.modal.close();
+            }
+            KeyCode::Up => {
+                *y = y.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                *y = (*y + 1).min(height.saturating_sub(1));
+            }
+            KeyCode::Left => {
+                *x = x.saturating_sub(1);
+            }
+            KeyCode::Right => {
+                *x = (*x + 1).min(width.saturating_sub(1));
+            }
+            KeyCode::Tab => {
+                let seeds = self.core.garden().seeds();
+                *seed = seeds
+                    .range(*seed..)
+                    .nth(1)
+                    .or_else(|| seeds.first())
+                    .copied()
+                    .unwrap_or(Seed::BakerWheat);
+            }
+            KeyCode::Char('p') => {
+                let (x, y, seed) = (*x, *y, *seed);
+                if !self.act(Action::PlantGardenSeed { x, y, seed }) {
+                    self.iface.add_flash(AppFlash::CantPlantSeed(seed));
+                }
+            }
+            KeyCode::Char('x') => {
+                let (x, y) = (*x, *y);
+                self.act(Action::HarvestGardenPlant { x, y });
+            }
+            KeyCode::Char('o') => {
+                let soils = Soil::VARIANTS;
+                let cur = soils
+                    .iter()
+                    .position(|s| *s == self.core.garden().soil())
+                    .unwrap_or(0);
+                let soil = soils[(cur + 1) % soils.len()];
+                if !self.act(Action::SetGardenSoil { soil }) {
+                    self.iface.add_flash(AppFlash::CantUseSoil(soil));
+                }
+            }
             _ => {}
         }
     }
@@ -169,19 +477,24 @@ impl App {
                 #[allow(clippy::collapsible_else_if)]
                 match self.list.pointee(&self.core) {
                     Some(AppListPointee::Building(building)) => {
-                        if self.iface.sell_mode() {
-                            if !self.core.sell_building(building) {
+                        let sell_mode = self.iface.sell_mode();
+                        let n =
+                            self.core
+                                .building_bulk_count(building, self.iface.bulk(), sell_mode);
+
+                        if sell_mode {
+                            if !self.act(Action::SellBuilding { building, count: n }) {
                                 self.iface
                                     .add_flash(AppFlash::CantSellUnownedBuilding(building));
                             }
                         } else {
-                            if !self.core.buy_building(building) {
+                            if !self.act(Action::BuyBuilding { building, count: n }) {
                                 self.iface.add_flash(AppFlash::CantAffordBuilding(building));
                             }
                         }
                     }
                     Some(AppListPointee::Upgrade(upgrade)) => {
-                        if !self.core.buy_upgrade(upgrade) {
+                        if !self.act(Action::BuyUpgrade { upgrade }) {
                             self.iface.add_flash(AppFlash::CantAffordUpgrade(upgrade));
                         }
                     }
@@ -198,11 +511,11 @@ impl App {
                 }
             }
             KeyCode::Char(' ') => {
-                self.core.click_cookie();
+                self.act(Action::ClickCookie);
                 self.iface.set_pressed_cookie();
             }
             KeyCode::Char(ch @ '1'..='9') => {
-                if self.core.click_golden_cookie(ch) {
+                if self.act(Action::ClickGoldenCookie { ch }) {
                     self.audio.golden_cookie_click();
                 }
             }
@@ -215,16 +528,82 @@ impl App {
             KeyCode::Char('r') => {
                 self.modal.set_renaming_bakery();
             }
+            KeyCode::Char('l') => {
+                if let Some(AppListPointee::Building(building)) = self.list.pointee(&self.core)
+                    && self.core.sugar_lumps().unlocked()
+                    && !self.act(Action::LevelUpBuilding { building })
+                {
+                    self.iface
+                        .add_flash(AppFlash::CantAffordBuildingLevel(building));
+                }
+            }
+            KeyCode::Char('g') => {
+                match self.act_with(Action::HarvestSugarLump, Core::harvest_sugar_lump) {
+                    Some(0) => self.iface.add_flash(AppFlash::SugarLumpBotched),
+                    Some(count) => self.iface.add_flash(AppFlash::SugarLumpHarvested(count)),
+                    None => {}
+                }
+            }
             KeyCode::Char('s') => {
                 if self.list.is_pane_highlighted(AppListPane::Buildings) {
                     self.iface.toggle_sell_mode();
                 }
             }
+            KeyCode::Char('b') => {
+                if self.list.is_pane_highlighted(AppListPane::Buildings) {
+                    self.iface.cycle_bulk();
+                }
+            }
             KeyCode::Char('w') => {
                 if !self.core.grandmapocalypse().wrinklers().is_empty() {
                     self.modal.set_wrinklers();
                 }
             }
+            KeyCode::Char('a') => {
+                if self.core.prestige_level_gain() >= 1.0 {
+                    self.modal.set_ascending();
+                }
+            }
+            KeyCode::Char('h') => {
+                if self.core.prestige().ascensions() > 0 {
+                    self.modal.set_heavenly_upgrades();
+                }
+            }
+            KeyCode::Char('m') => {
+                if self.core.stock_market_unlocked() {
+                    self.modal.set_stock_market();
+                }
+            }
+            KeyCode::Char('c') => {
+                if self.core.grimoire_unlocked() {
+                    self.modal.set_grimoire();
+                }
+            }
+            KeyCode::Char('t') => {
+                if self.core.pantheon_unlocked() {
+                    self.modal.set_pantheon();
+                }
+            }
+            KeyCode::Char('f') => {
+                if self.core.garden_plot_size().0 > 0 {
+                    self.modal.set_garden();
+                }
+            }
+            KeyCode::Char('e') => {
+                if self.core.santa_level_cost().is_some() {
+                    if self.act(Action::LevelUpSanta) {
+                        self.iface
+                            .add_flash(AppFlash::SantaLeveledUp(self.core.santa().level()));
+                    } else {
+                        self.iface.add_flash(AppFlash::CantLevelUpSanta);
+                    }
+                }
+            }
+            KeyCode::Char('k') => {
+                if self.core.dragon().laid() {
+                    self.modal.set_dragon();
+                }
+            }
             KeyCode::Char('.') => {
                 self.debug.backward();
             }
@@ -258,6 +637,10 @@ impl App {
     }
 
     async fn tick(&mut self) -> Result<()> {
+        for action in self.automation.tick(&self.core) {
+            self.act(action);
+        }
+
         let changeset = self.core.tick();
 
         self.iface.tick();
@@ -272,7 +655,8 @@ impl App {
             self.iface.add_flash(AppFlash::ResearchCompleted);
         }
 
-        if self.save.notify_just_saved() {
+        let just_saved = self.save.notify_just_saved();
+        if just_saved {
             self.iface.add_flash(AppFlash::Saved);
         }
 
@@ -283,11 +667,28 @@ impl App {
         self.tick.tick();
         self.changeset.tick(changeset);
 
+        // the replay is kept up to date with the save, so a crash doesn't lose it
+        if just_saved {
+            self.replay.write(self.tick.tick_no()).await?;
+        }
+
         Ok(())
     }
 
+    // applies an action to the core and records it for replays
+    fn act(&mut self, action: Action) -> bool {
+        self.act_with(action, |core| core.apply_action(action))
+    }
+
+    // for actions whose outcome is needed, `apply` has to do what `action` does
+    fn act_with<T>(&mut self, action: Action, apply: impl FnOnce(&mut Core) -> T) -> T {
+        self.replay.record_action(self.tick.tick_no(), action);
+        apply(&mut self.core)
+    }
+
     async fn quit(&mut self) -> Result<()> {
         self.quit = true;
-        self.save.save(&self.core, self.bakery.name()).await
+        self.save.save(&self.core, self.bakery.name()).await?;
+        self.replay.write(self.tick.tick_no()).await
     }
 }
@@ -1,3 +1,4 @@
+use cookie_clicker_tui_core::{OfflineProgress, Seed};
 use tui_widget_list::ListState;
 
 #[derive(Default)]
@@ -9,6 +10,26 @@ pub enum AppModalState {
         state: ListState,
     },
     RenamingBakery(String),
+    Ascending,
+    HeavenlyUpgrades {
+        state: ListState,
+    },
+    Offline(OfflineProgress),
+    StockMarket {
+        state: ListState,
+    },
+    Grimoire,
+    Pantheon {
+        state: ListState,
+    },
+    Garden {
+        x: usize,
+        y: usize,
+        seed: Seed,
+    },
+    Dragon {
+        state: ListState,
+    },
 }
 
 impl AppModalState {
@@ -38,6 +59,46 @@ impl AppModalState {
         };
     }
 
+    pub(super) fn set_ascending(&mut self) {
+        *self = Self::Ascending;
+    }
+
+    pub(super) fn set_heavenly_upgrades(&mut self) {
+        *self = Self::HeavenlyUpgrades {
+            state: ListState::default(),
+        };
+    }
+
+    pub(super) fn set_stock_market(&mut self) {
+        *self = Self::StockMarket {
+            state: ListState::default(),
+        };
+    }
+
+    pub(super) fn set_grimoire(&mut self) {
+        *self = Self::Grimoire;
+    }
+
+    pub(super) fn set_pantheon(&mut self) {
+        *self = Self::Pantheon {
+            state: ListState::default(),
+        };
+    }
+
+    pub(super) fn set_garden(&mut self) {
+        *self = Self::Garden {
+            x: 0,
+            y: 0,
+            seed: Seed::BakerWheat,
+        };
+    }
+
+    pub(super) fn set_dragon(&mut self) {
+        *self = Self::Dragon {
+            state: ListState::default(),
+        };
+    }
+
     pub(super) fn close(&mut self) {
         *self = Self::None;
     }
@@ -1,12 +1,14 @@
 mod app;
 mod audio;
 mod event;
+mod replay;
 mod save;
 mod ui;
 
 use self::{
-    app::App,
+    app::{App, AppAutomationState, AutobuyPolicy},
     audio::Audio,
+    replay::{Replay, ReplayData},
     save::{Save, SaveData, SaveOptions},
 };
 use anyhow::Result;
@@ -14,6 +16,8 @@ use clap::Parser;
 use cookie_clicker_tui_core::{Building, GrandmapocalypsePhase};
 use std::path::Path;
 
+const OFFLINE_MIN_SECS: f64 = 60.0;
+
 #[derive(Parser)]
 struct Cli {
     /// The path to the save file
@@ -27,6 +31,10 @@ struct Cli {
     #[clap(long, conflicts_with = "dry_run")]
     notify_on_autosave: bool,
 
+    /// The fraction of cookies per second earned while the game is closed
+    #[clap(long, default_value_t = 0.5, conflicts_with = "dry_run")]
+    offline_efficiency: f64,
+
     /// Make all purchases free
     #[clap(long, requires = "dry_run")]
     free_everything: bool,
@@ -41,13 +49,33 @@ struct Cli {
     /// Greatly speeds up golden cookie spawning
     #[clap(long, requires = "dry_run")]
     fast_golden_cookies: bool,
+
+    /// Record every action into a replay file
+    #[clap(long)]
+    record: Option<Box<Path>>,
+
+    /// Re-drive the game through a replay file, then keep playing without saving
+    #[clap(long, conflicts_with_all = ["path", "dry_run", "record"])]
+    replay: Option<Box<Path>>,
+
+    /// Click the big cookie this many times per second, up to 100, marks the save as cheated
+    #[clap(long)]
+    autoclick: Option<f64>,
+
+    /// Click every golden cookie as soon as it appears, marks the save as cheated
+    #[clap(long)]
+    auto_golden_cookies: bool,
+
+    /// Buy buildings and upgrades automatically, marks the save as cheated
+    #[clap(long, value_enum)]
+    autobuy: Option<AutobuyPolicy>,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let save_options = if cli.dry_run {
+    let save_options = if cli.dry_run || cli.replay.is_some() {
         SaveOptions::Dry
     } else {
         SaveOptions::Persisted {
@@ -57,10 +85,30 @@ async fn main() -> Result<()> {
     };
 
     let mut save = Save::new(save_options)?;
-    let SaveData {
-        bakery_name,
-        mut core,
-    } = save.data().await?;
+
+    let (bakery_name, mut core, offline) = match &cli.replay {
+        Some(path) => {
+            let (core, bakery_name) = ReplayData::read(path).await?.drive();
+            (bakery_name, core, None)
+        }
+        None => {
+            let data = save.data().await?;
+            let secs_offline = data.secs_since_saved();
+            let SaveData {
+                bakery_name,
+                mut core,
+                ..
+            } = data;
+
+            let offline = secs_offline
+                .filter(|secs| *secs >= OFFLINE_MIN_SECS)
+                .map(|secs| {
+                    core.fast_forward_offline(secs, cli.offline_efficiency.clamp(0.0, 1.0))
+                });
+
+            (bakery_name, core, offline)
+        }
+    };
 
     if cli.free_everything {
         core.cheat_make_everything_free();
@@ -79,10 +127,22 @@ async fn main() -> Result<()> {
         core.cheat_spawn_golden_cookies_fast();
     }
 
+    let automation = AppAutomationState::new(
+        cli.autoclick.unwrap_or_default(),
+        cli.auto_golden_cookies,
+        cli.autobuy,
+    );
+
+    if automation.enabled() {
+        core.mark_cheated();
+    }
+
+    let replay = Replay::new(cli.record, &core, bakery_name.as_deref())?;
+
     let mut term = ratatui::init();
     let audio = Audio::new()?;
 
-    let app = App::new(save, core, bakery_name, audio);
+    let app = App::new(save, replay, core, bakery_name, audio, automation, offline);
     let res = app.run(&mut term).await;
 
     ratatui::restore();
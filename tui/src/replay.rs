@@ -0,0 +1,144 @@
+use anyhow::{Context, Result};
+use cookie_clicker_tui_core::{Action, Core};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ReplayEntry {
+    tick: u64,
+    #[serde(flatten)]
+    event: ReplayEvent,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+enum ReplayEvent {
+    Action(Action),
+    Rename { rename: Box<str> },
+}
+
+#[derive(Deserialize)]
+pub struct ReplayData {
+    #[serde(default)]
+    bakery_name: Option<Box<str>>,
+    core: Core,
+    ticks: u64,
+    entries: Vec<ReplayEntry>,
+}
+
+impl ReplayData {
+    pub async fn read(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .await
+            .with_context(|| format!("failed to read replay file '{path:?}'"))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("failed to parse replay file '{path:?}'"))
+    }
+
+    // drives the core the same way the app did while recording
+    pub fn drive(self) -> (Core, Option<Box<str>>) {
+        let Self {
+            mut bakery_name,
+            mut core,
+            ticks,
+            entries,
+        } = self;
+
+        let mut entries = entries.into_iter().peekable();
+
+        for tick in 0..=ticks {
+            while let Some(entry) = entries.next_if(|e| e.tick == tick) {
+                match entry.event {
+                    ReplayEvent::Action(action) => {
+                        core.apply_action(action);
+                    }
+                    ReplayEvent::Rename { rename } => {
+                        bakery_name = (!rename.is_empty()).then_some(rename);
+                    }
+                }
+            }
+
+            if tick < ticks {
+                let _ = core.tick();
+            }
+        }
+
+        (core, bakery_name)
+    }
+}
+
+#[derive(Serialize)]
+struct ReplayDataRef<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bakery_name: Option<&'a str>,
+    core: &'a serde_json::Value,
+    ticks: u64,
+    entries: &'a [ReplayEntry],
+}
+
+#[derive(Debug)]
+pub struct Replay(Option<Recording>);
+
+#[derive(Debug)]
+struct Recording {
+    path: Box<Path>,
+    bakery_name: Option<Box<str>>,
+    core: serde_json::Value,
+    entries: Vec<ReplayEntry>,
+}
+
+impl Replay {
+    // the core is snapshot right away, so this has to happen before anything touches it
+    pub fn new(path: Option<Box<Path>>, core: &Core, bakery_name: Option<&str>) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self(None));
+        };
+
+        let core = serde_json::to_value(core).context("could not snapshot core for replay")?;
+
+        Ok(Self(Some(Recording {
+            path,
+            bakery_name: bakery_name.map(Into::into),
+            core,
+            entries: Vec::new(),
+        })))
+    }
+
+    pub fn record_action(&mut self, tick: u64, action: Action) {
+        self.record(tick, ReplayEvent::Action(action));
+    }
+
+    pub fn record_rename(&mut self, tick: u64, name: &str) {
+        self.record(
+            tick,
+            ReplayEvent::Rename {
+                rename: name.into(),
+            },
+        );
+    }
+
+    fn record(&mut self, tick: u64, event: ReplayEvent) {
+        if let Some(recording) = &mut self.0 {
+            recording.entries.push(ReplayEntry { tick, event });
+        }
+    }
+
+    pub async fn write(&self, ticks: u64) -> Result<()> {
+        let Some(recording) = &self.0 else {
+            return Ok(());
+        };
+
+        let data = ReplayDataRef {
+            bakery_name: recording.bakery_name.as_deref(),
+            core: &recording.core,
+            ticks,
+            entries: &recording.entries,
+        };
+
+        let json = serde_json::to_string(&data).context("could not serialize replay")?;
+        fs::write(&recording.path, json)
+            .await
+            .context("could not write replay")
+    }
+}
@@ -2,20 +2,35 @@ use anyhow::{Context, Result};
 use cookie_clicker_tui_core::Core;
 use cookie_clicker_tui_utils::refresh::Refresh;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tokio::fs;
 
 #[derive(Deserialize, Default)]
 pub struct SaveData {
     #[serde(default)]
     pub bakery_name: Option<Box<str>>,
+    #[serde(default)]
+    pub saved_at: Option<u64>,
     pub core: Core,
 }
 
+impl SaveData {
+    pub fn secs_since_saved(&self) -> Option<f64> {
+        let saved_at = self.saved_at?;
+        let now = unix_secs()?;
+        Some(now.saturating_sub(saved_at) as f64)
+    }
+}
+
 #[derive(Serialize)]
 struct SaveDataRef<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     bakery_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    saved_at: Option<u64>,
     core: &'a Core,
 }
 
@@ -188,7 +203,11 @@ impl Save {
             return Ok(());
         };
 
-        let data = SaveDataRef { bakery_name, core };
+        let data = SaveDataRef {
+            bakery_name,
+            saved_at: unix_secs(),
+            core,
+        };
         #[cfg(debug_assertions)]
         let res = serde_json::to_string_pretty(&data);
         #[cfg(not(debug_assertions))]
@@ -223,6 +242,13 @@ impl Save {
     }
 }
 
+fn unix_secs() -> Option<u64> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
 #[cfg(debug_assertions)]
 fn default_path() -> Result<Box<Path>> {
     Ok(std::env::current_dir()
@@ -1,5 +1,5 @@
 use super::{UiApp, utils::num::PrintFloat};
-use cookie_clicker_tui_core::{AchievementReq, Building};
+use cookie_clicker_tui_core::{AchievementReq, Building, Season};
 use cookie_clicker_tui_utils::str::pluralized;
 use ratatui::{
     prelude::*,
@@ -49,6 +49,11 @@ pub fn achievement(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
         )
         .into(),
         AchievementReq::SellAGrandma => "• sell a grandma".into(),
+        AchievementReq::BuildingsSold(n) => format!(
+            "• sell {n} {} in all",
+            pluralized(n as _, "building", "buildings")
+        )
+        .into(),
         AchievementReq::Cps(n) => format!(
             "• bake {} {} per second",
             n.print_float(0, 0),
@@ -69,6 +74,12 @@ pub fn achievement(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
         AchievementReq::GoldenCookieClickedAtMost1sBeforeDespawn => {
             "• click a golden cookie at the last second".into()
         }
+        AchievementReq::GoldenCookiesOnScreen(n) => {
+            format!("• have {n} golden cookies on screen at once").into()
+        }
+        AchievementReq::WrathCookieClickedCount(n) => {
+            format!("• click {n} wrath {}", pluralized(n, "cookie", "cookies")).into()
+        }
         AchievementReq::GrandmapocalypseStarted => "• trigger the grandmapocalypse".into(),
         AchievementReq::GrandmapocalypseTemporarilyAppeasedTimes(n) => format!(
             "• appease the grandmatriarchs {n} {}",
@@ -82,6 +93,17 @@ pub fn achievement(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
             format!("• pop {n} {}", pluralized(n, "wrinkler", "wrinklers")).into()
         }
         AchievementReq::ShinyWrinklerPopped => "• pop the near-extinct shiny wrinkler".into(),
+        AchievementReq::ReindeerClicked(n) => {
+            format!("• pop {n} {}", pluralized(n, "reindeer", "reindeer")).into()
+        }
+        AchievementReq::SeasonDrops(season, n) => {
+            let (singular, plural) = season_drop_noun(season);
+            format!("• unlock {n} {}", pluralized(n, singular, plural)).into()
+        }
+        AchievementReq::SeasonDropsAll(season) => {
+            format!("• unlock every {}", season_drop_noun(season).0).into()
+        }
+        AchievementReq::Cheated => "• play with automation on".into(),
     };
 
     let area = split_area(area);
@@ -94,6 +116,16 @@ pub fn achievement(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
     Paragraph::new(req).block(block).render(area, buf);
 }
 
+fn season_drop_noun(season: Season) -> (&'static str, &'static str) {
+    match season {
+        Season::BusinessDay => ("seasonal cookie", "seasonal cookies"),
+        Season::Christmas => ("christmas cookie", "christmas cookies"),
+        Season::Easter => ("egg", "eggs"),
+        Season::Halloween => ("halloween cookie", "halloween cookies"),
+        Season::Valentines => ("heart biscuit", "heart biscuits"),
+    }
+}
+
 fn split_area(area: Rect) -> Rect {
     let (percent_x, percent_y) = SCREEN_PERCENT;
     let vert = Layout::vertical([
@@ -3,7 +3,9 @@ use super::{
     utils::shop::{ShopItemRender, ShopItemWidget},
 };
 use crate::app::AppListPane;
-use cookie_clicker_tui_core::{Building, BuildingInfo, Core, CostResolved, GrandmapocalypsePhase};
+use cookie_clicker_tui_core::{
+    Building, BuildingInfo, Core, CostResolved, GrandmapocalypsePhase, Purchase,
+};
 use ratatui::{
     prelude::*,
     widgets::{Block, Padding},
@@ -18,10 +20,13 @@ pub fn buildings(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
         let info = app.core.building_info_nth(ctx.index);
 
         let sell_mode = app.iface.sell_mode();
+        let bulk_count = app
+            .core
+            .building_bulk_count(info.building(), app.iface.bulk(), sell_mode);
         let affordable = if sell_mode {
             info.count() > 0
         } else {
-            app.core.affordable(info.cost())
+            app.core.affordable(info.bulk_cost(bulk_count))
         };
 
         let grandmapocalypse_phase = app
@@ -34,6 +39,7 @@ pub fn buildings(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
             core: app.core,
             info,
             sell_mode,
+            bulk_count,
             grandmapocalypse_phase,
         };
 
@@ -48,16 +54,17 @@ pub fn buildings(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
 
     let list_view = ListView::new(builder, Building::VARIANT_COUNT);
 
+    let bulk = app.iface.bulk();
     let (title, controls, border_style) = if app.iface.sell_mode() {
         (
-            " SELL Buildings ",
-            " Sell <Enter> Buy Mode <S> Inspect <I> ",
+            format!(" SELL Buildings {} ", bulk.name()),
+            " Sell <Enter> Buy Mode <S> Amount <B> Inspect <I> ",
             Style::new().red(),
         )
     } else {
         (
-            " Buildings ",
-            " Buy <Enter> Sell Mode <S> Inspect <I> ",
+            format!(" Buildings {} ", bulk.name()),
+            " Buy <Enter> Sell Mode <S> Amount <B> Inspect <I> ",
             Style::new(),
         )
     };
@@ -75,6 +82,7 @@ struct BuildingInfoShopItem<'a> {
     core: &'a Core,
     info: BuildingInfo<'a>,
     sell_mode: bool,
+    bulk_count: u16,
     grandmapocalypse_phase: Option<GrandmapocalypsePhase>,
 }
 
@@ -90,9 +98,10 @@ impl ShopItemRender for BuildingInfoShopItem<'_> {
 
     fn cost(&self) -> CostResolved {
         if self.sell_mode {
-            self.core.resolve_cost(self.info.sell_cost())
+            self.core
+                .resolve_cost(self.info.bulk_sell_cost(self.bulk_count))
         } else {
-            self.core.resolve_cost(self.info.cost())
+            self.core.resolve_cost(self.info.bulk_cost(self.bulk_count))
         }
     }
 
@@ -103,4 +112,8 @@ impl ShopItemRender for BuildingInfoShopItem<'_> {
             GrandmapocalypsePhase::Angered => (" >>>:((", Style::new().red()),
         })
     }
+
+    fn best_buy(&self) -> bool {
+        !self.sell_mode && self.core.best_buy() == Some(Purchase::Building(self.info.building()))
+    }
 }
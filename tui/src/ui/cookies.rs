@@ -1,5 +1,8 @@
 use super::{UiApp, utils::num::PrintFloat};
-use cookie_clicker_tui_core::MilkFlavor;
+use cookie_clicker_tui_core::{
+    BuffSource, MilkFlavor, SantaLevel, Season, SugarLumpKind, SugarLumpStage,
+};
+use cookie_clicker_tui_utils::str::duration;
 use ratatui::{
     prelude::*,
     widgets::{Block, Paragraph},
@@ -31,19 +34,52 @@ pub fn cookies(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
 
     cookie_count(app, &mut lines);
     cps_count(app, &mut lines);
+    prestige(app, &mut lines);
+    season(app, &mut lines);
+    buffs(app, &mut lines);
     lines.push(Line::default());
     logo(app, &mut lines);
 
-    let title: Cow<str> = match app.bakery.name() {
+    let mut title: Cow<str> = match app.bakery.name() {
         Some(name) => format!(" {name} ").into(),
         None => " Cookies ".into(),
     };
+    if app.core.cheated() {
+        title.to_mut().push_str("[Cheated] ");
+    }
 
     let controls = {
         let mut controls = Cow::Borrowed(" Click <Space> Rename <R> ");
         if !app.core.grandmapocalypse().wrinklers().is_empty() {
             controls.to_mut().push_str("Wrinklers <W> ");
         }
+        if app.core.prestige_level_gain() >= 1.0 {
+            controls.to_mut().push_str("Ascend <A> ");
+        }
+        if app.core.prestige().ascensions() > 0 {
+            controls.to_mut().push_str("Heavenly <H> ");
+        }
+        if app.core.garden_plot_size().0 > 0 {
+            controls.to_mut().push_str("Garden <F> ");
+        }
+        if app.core.stock_market_unlocked() {
+            controls.to_mut().push_str("Market <M> ");
+        }
+        if app.core.grimoire_unlocked() {
+            controls.to_mut().push_str("Grimoire <C> ");
+        }
+        if app.core.pantheon_unlocked() {
+            controls.to_mut().push_str("Pantheon <T> ");
+        }
+        if app.core.santa_level_cost().is_some() {
+            controls.to_mut().push_str("Santa <E> ");
+        }
+        if app.core.dragon().laid() {
+            controls.to_mut().push_str("Dragon <K> ");
+        }
+        if app.core.sugar_lumps().harvestable() {
+            controls.to_mut().push_str("Harvest <G> ");
+        }
         controls
     };
 
@@ -54,6 +90,7 @@ pub fn cookies(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
     let block_area = block.inner(area);
 
     milk_wave(app, block_area, buf);
+    santa(app, block_area, buf);
     wrinklers(app, block_area, buf);
     sugar_lump(app, block_area, buf);
 
@@ -95,6 +132,67 @@ fn cps_count(app: &mut UiApp, lines: &mut Vec<Line>) {
     ));
 }
 
+fn prestige(app: &mut UiApp, lines: &mut Vec<Line>) {
+    let level = app.core.prestige().level();
+
+    if level == 0.0 {
+        return;
+    }
+
+    lines.push(Line::styled(
+        format!(
+            "(prestige level {}: +{}% per second)",
+            level.print_float(0, 0),
+            level.print_float(0, 0)
+        ),
+        Style::new().light_magenta().italic(),
+    ));
+}
+
+fn season(app: &mut UiApp, lines: &mut Vec<Line>) {
+    let seasons = app.core.seasons();
+
+    let (Some(season), Some(secs)) = (seasons.current(), seasons.secs_left()) else {
+        return;
+    };
+
+    lines.push(Line::styled(
+        format!("({} season: {} left)", season.name(), duration(secs)),
+        Style::new().light_cyan().italic(),
+    ));
+}
+
+fn buffs(app: &mut UiApp, lines: &mut Vec<Line>) {
+    for buff in app.core.buffs().iter() {
+        let name: Cow<str> = match buff.source() {
+            BuffSource::BuildingSpecial(building) => format!("{} special", building.name()).into(),
+            source => source.name().into(),
+        };
+
+        let effect = match buff.source() {
+            BuffSource::HagglersLuck => "upgrades are cheaper".to_owned(),
+            BuffSource::HagglersMisery => "upgrades are pricier".to_owned(),
+            _ if buff.cpc_gain() != 0.0 => format!(
+                "+{} cookies per click, no production",
+                buff.cpc_gain().print_float(0, 1)
+            ),
+            _ if buff.cpc_mult() != 1.0 => format!(
+                "{TIMES}{} cookies per click",
+                buff.cpc_mult().print_float(0, 1)
+            ),
+            _ => format!(
+                "{TIMES}{} cookies per second",
+                buff.cps_mult().print_float(0, 1)
+            ),
+        };
+
+        lines.push(Line::styled(
+            format!("{name}: {effect} ({}s)", buff.secs_left().ceil()),
+            Style::new().yellow().bold(),
+        ));
+    }
+}
+
 fn logo(app: &mut UiApp, lines: &mut Vec<Line>) {
     for line_text in LOGO.lines() {
         let line_text = if app.iface.pressed_cookie() && app.tick.tick_no() % 2 == 0 {
@@ -159,6 +257,29 @@ fn milk_style(flavor: MilkFlavor) -> Style {
     }
 }
 
+fn santa(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    if !app.core.seasons().is(Season::Christmas) {
+        return;
+    }
+
+    let level = app.core.santa().level();
+
+    let mut spans = vec![
+        Span::styled(" ❄ ", Color::LightRed),
+        Span::styled(level.name(), Modifier::BOLD),
+        Span::raw(format!(" {}/{}", level.number(), SantaLevel::MAX.number())),
+    ];
+
+    if let Some(cost) = app.core.santa_level_cost() {
+        spans.push(Span::raw(format!(" · next {}", cost.print_float(0, 0))));
+    }
+
+    // sits on the milk wave
+    let area = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(area)[1];
+
+    Line::from(spans).render(area, buf);
+}
+
 fn wrinklers(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
     const EMOTE: &str = " [^ (▼▼▼) ^]";
 
@@ -190,10 +311,33 @@ fn sugar_lump(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
 
     let count = sugar_lumps.count();
     let text: Cow<str> = if count == 0 {
-        "𖧋  ".into() // spaces used as padding :(
+        "𖧋 ".into()
     } else {
-        format!("{count} {TIMES} ●  ").into()
+        format!("{count} {TIMES} ● ").into()
     };
 
-    Line::raw(text).right_aligned().render(area, buf);
+    let stage = match sugar_lumps.stage() {
+        Some(SugarLumpStage::Growing) | None => "·",
+        Some(SugarLumpStage::Mature) => "◒",
+        Some(SugarLumpStage::Ripe) => "◉",
+        Some(SugarLumpStage::Overripe) => "◍",
+    };
+
+    let color = match sugar_lumps.kind() {
+        Some(SugarLumpKind::Normal) | None => Color::Reset,
+        Some(SugarLumpKind::Bifurcated) => Color::Cyan,
+        Some(SugarLumpKind::Golden) => Color::Yellow,
+        Some(SugarLumpKind::Meaty) => Color::Red,
+        Some(SugarLumpKind::Caramelized) => Color::LightRed,
+    };
+
+    // spaces used as padding :(
+    Line::from(vec![
+        Span::raw(text),
+        Span::raw("| "),
+        Span::styled(stage, color),
+        Span::raw("  "),
+    ])
+    .right_aligned()
+    .render(area, buf);
 }
@@ -24,6 +24,7 @@ pub fn debug(app: &mut UiApp, view: AppDebugView, area: Rect, buf: &mut Buffer)
         AppDebugView::Research => format!("{:?}", core.research()),
         AppDebugView::Grandmapocalypse => format!("{:?}", core.grandmapocalypse()),
         AppDebugView::GoldenCookies => format!("{:?}", core.golden_cookies()),
+        AppDebugView::Buffs => format!("{:?}", core.buffs()),
         AppDebugView::News => format!("{:?}", app.news),
         AppDebugView::List => format!("{:?}", app.list.debug(core)),
         AppDebugView::Interface => format!("{:?}", app.iface),
@@ -21,6 +21,13 @@ pub fn flash(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
         }
         AppFlash::ResearchCompleted => "• research completed".into(),
         AppFlash::SugarLumpsUnlocked => "• sugar lumps unlocked".into(),
+        AppFlash::Ascended => "• ascended! welcome to your new bakery".into(),
+        AppFlash::SugarLumpHarvested(count) => format!(
+            "• harvested {count} sugar {}",
+            if count == 1 { "lump" } else { "lumps" }
+        )
+        .into(),
+        AppFlash::SugarLumpBotched => "• the sugar lump wasn't ripe yet and crumbled away".into(),
         AppFlash::CantAffordBuilding(building) => format!(
             "• you can't afford {}",
             indefinite_article(building.name_lower())
@@ -29,6 +36,51 @@ pub fn flash(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
         AppFlash::CantAffordUpgrade(upgrade) => {
             format!("• you can't afford {}", upgrade.name_lower()).into()
         }
+        AppFlash::CantAffordBuildingLevel(building) => format!(
+            "• you don't have enough sugar lumps to level up your {}",
+            building.name_lower_plural()
+        )
+        .into(),
+        AppFlash::CantAffordHeavenlyUpgrade(upgrade) => {
+            format!("• you can't afford {} yet", upgrade.name_lower()).into()
+        }
+        AppFlash::HeavenlyUpgradeAlreadyOwned(upgrade) => {
+            format!("• you already own {}", upgrade.name_lower()).into()
+        }
+        AppFlash::HeavenlyUpgradeLocked(upgrade) => format!(
+            "• you need to buy the upgrades leading to {} first",
+            upgrade.name_lower()
+        )
+        .into(),
+        AppFlash::CantPlantSeed(seed) => {
+            format!("• you can't plant {} here", seed.name_lower()).into()
+        }
+        AppFlash::CantUseSoil(soil) => format!(
+            "• you need {} farms to use {}",
+            soil.farms_req(),
+            soil.name_lower()
+        )
+        .into(),
+        AppFlash::SpellCast(spell) => format!("• you cast {}", spell.name()).into(),
+        AppFlash::SpellBackfired(spell) => format!("• {} backfired!", spell.name()).into(),
+        AppFlash::NotEnoughMagic(spell) => {
+            format!("• you don't have enough magic to cast {}", spell.name()).into()
+        }
+        AppFlash::NoWorshipSwaps => "• you have no worship swaps left".into(),
+        AppFlash::SantaLeveledUp(level) => {
+            format!("• santa has grown into {}", level.name_lower()).into()
+        }
+        AppFlash::CantLevelUpSanta => "• you can't afford to feed santa yet".into(),
+        AppFlash::CantTrainDragon => "• you can't afford to train krumblor yet".into(),
+        AppFlash::CantSetDragonAura(aura) => {
+            format!("• krumblor can't use {} there yet", aura.name()).into()
+        }
+        AppFlash::CantBuyStock(good) => {
+            format!("• you can't afford or store any more {}", good.name_lower()).into()
+        }
+        AppFlash::CantSellStock(good) => {
+            format!("• you don't have any {} to sell", good.name_lower()).into()
+        }
         AppFlash::CantSellUnownedBuilding(building) => format!(
             "• you don't have {} to sell",
             indefinite_article(building.name_lower())
@@ -1,5 +1,5 @@
 use super::UiApp;
-use cookie_clicker_tui_core::GoldenCookie;
+use cookie_clicker_tui_core::{GoldenCookie, GoldenCookieKind};
 use ratatui::{
     prelude::*,
     widgets::{Block, Clear, Paragraph},
@@ -29,7 +29,12 @@ fn golden_cookie(cookie: &GoldenCookie, area: Rect, buf: &mut Buffer) {
     };
 
     let lines = LOGO.lines().map(Line::raw).collect::<Vec<_>>();
-    let block = Block::bordered().black().on_yellow().title_bottom(
+    let bg = match cookie.kind() {
+        GoldenCookieKind::Golden => Color::Yellow,
+        GoldenCookieKind::Wrath => Color::Red,
+        GoldenCookieKind::Reindeer => Color::LightRed,
+    };
+    let block = Block::bordered().black().bg(bg).title_bottom(
         Line::styled(format!(" Click <{}> ", cookie.ch()), Modifier::BOLD).right_aligned(),
     );
 
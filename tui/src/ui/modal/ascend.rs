@@ -0,0 +1,57 @@
+use super::{
+    super::{UiApp, utils::num::PrintFloat},
+    ModalImpl,
+};
+use ratatui::{prelude::*, widgets::Paragraph};
+
+pub fn ascend(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: " Ascend ".into(),
+        title_bottom: " Ascend <Enter> Close <Esc> ".into(),
+        screen_percent: (50, 25),
+    };
+
+    modal.render(|area, buf, block| {
+        let prestige = app.core.prestige();
+        let gain = app.core.prestige_level_gain();
+
+        let level_line = Line::from(vec![
+            Span::raw("• prestige level "),
+            Span::styled(
+                format!("{}", prestige.level().print_float(0, 0)),
+                Modifier::BOLD,
+            ),
+            Span::raw(" → "),
+            Span::styled(
+                format!("{}", (prestige.level() + gain).print_float(0, 0)),
+                Modifier::BOLD,
+            ),
+        ]);
+
+        let chips_line = Line::from(vec![
+            Span::raw("• gain "),
+            Span::styled(format!("{}", gain.print_float(0, 0)), Modifier::BOLD),
+            Span::raw(" heavenly chips"),
+        ]);
+
+        let cps_line = Line::from(vec![
+            Span::raw("• +"),
+            Span::styled(
+                format!("{}%", (prestige.level() + gain).print_float(0, 0)),
+                Modifier::BOLD,
+            ),
+            Span::raw(" cookies per second"),
+        ]);
+
+        let warning_line = Line::styled(
+            "• your bakery, buildings and upgrades will be lost",
+            Style::new().red(),
+        );
+
+        Paragraph::new(vec![level_line, chips_line, cps_line, warning_line])
+            .block(block)
+            .render(area, buf);
+    });
+}
@@ -3,19 +3,31 @@ use super::{
     ModalImpl,
 };
 use cookie_clicker_tui_core::Building;
+use cookie_clicker_tui_utils::str::pluralized;
 use ratatui::{prelude::*, widgets::Paragraph};
+use std::borrow::Cow;
 
 pub fn building(app: &mut UiApp, building: Building, area: Rect, buf: &mut Buffer) {
     let info = app.core.building_info(building);
     let count = info.count();
     let name = building.name_pluralized(count as _);
     let title = format!(" {count} {name} ");
+    let title_bottom: Cow<str> = if app.core.sugar_lumps().unlocked() {
+        let lumps = info.level() + 1;
+        format!(
+            " Level Up <L> ({lumps} {}) Close <Esc> ",
+            pluralized(lumps as _, "lump", "lumps")
+        )
+        .into()
+    } else {
+        " Close <Esc> ".into()
+    };
 
     let modal = ModalImpl {
         area,
         buf,
         title: title.into(),
-        title_bottom: " Close <Esc> ".into(),
+        title_bottom,
         screen_percent: (60, 31),
     };
 
@@ -62,13 +74,37 @@ pub fn building(app: &mut UiApp, building: Building, area: Rect, buf: &mut Buffe
             Span::raw(" cookies in all time"),
         ]);
 
-        Paragraph::new(vec![
+        let mut lines = vec![
             cps_per_line,
             cps_line,
             cps_percent_line,
             cookies_all_time_line,
-        ])
-        .block(block)
-        .render(area, buf);
+        ];
+
+        lines.push(Line::from(vec![
+            Span::raw("• bought "),
+            Span::styled(format!("{}", info.bought()), Modifier::BOLD),
+            Span::raw(" and sold "),
+            Span::styled(format!("{}", info.sold()), Modifier::BOLD),
+            Span::raw(" in all time"),
+        ]));
+
+        lines.push(Line::from(vec![
+            Span::raw("• sold "),
+            Span::styled(format!("{}", app.core.buildings_sold()), Modifier::BOLD),
+            Span::raw(" buildings of every kind"),
+        ]));
+
+        if info.level() > 0 {
+            lines.push(Line::from(vec![
+                Span::raw("• level "),
+                Span::styled(format!("{}", info.level()), Modifier::BOLD),
+                Span::raw(", granting "),
+                Span::styled(format!("+{}%", info.level()), Modifier::BOLD),
+                Span::raw(" cookies per second"),
+            ]));
+        }
+
+        Paragraph::new(lines).block(block).render(area, buf);
     });
 }
@@ -0,0 +1,131 @@
+use super::ModalImpl;
+use crate::{
+    app::AppModalState,
+    ui::{
+        UiApp,
+        utils::{num::PrintFloat, style::StyleExt},
+    },
+};
+use cookie_clicker_tui_core::{Core, DragonAura, DragonAuraSlot, DragonTrainingCost};
+use ratatui::{prelude::*, widgets::Paragraph};
+use tui_widget_list::{ListBuilder, ListView};
+
+pub fn dragon(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    let dragon = app.core.dragon();
+    let title = format!(
+        " Krumblor, Cookie Dragon (level {}/{}) ",
+        dragon.level(),
+        dragon.max_level()
+    );
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: title.into(),
+        title_bottom: " Train <T> First <1> Second <2> Unslot <U> Close <Esc> ".into(),
+        screen_percent: (50, 50),
+    };
+
+    modal.render(|area, buf, block| {
+        let list_state = match app.modal {
+            AppModalState::Dragon { state } => state,
+            _ => unreachable!(),
+        };
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let rows = Layout::vertical([Constraint::Length(2), Constraint::Fill(1)])
+            .spacing(1)
+            .split(inner);
+
+        Paragraph::new(header_lines(app.core)).render(rows[0], buf);
+
+        let builder = ListBuilder::new(|ctx| {
+            let widget = AuraWidget {
+                core: app.core,
+                aura: DragonAura::VARIANTS[ctx.index],
+                selected: ctx.is_selected,
+            };
+
+            const HEIGHT: u16 = 2;
+
+            (widget, HEIGHT)
+        });
+
+        ListView::new(builder, DragonAura::VARIANT_COUNT).render(rows[1], buf, list_state);
+    });
+}
+
+fn header_lines(core: &Core) -> Vec<Line<'static>> {
+    let dragon = core.dragon();
+
+    let mut slots = Vec::new();
+    for slot in DragonAuraSlot::variants() {
+        slots.push(Span::raw(format!("{} aura: ", slot.name())));
+        let aura = if dragon.slot_unlocked(slot) {
+            dragon.aura(slot).map_or("—", |a| a.name())
+        } else {
+            "locked"
+        };
+        slots.push(Span::styled(aura, Modifier::BOLD));
+        slots.push(Span::raw("  "));
+    }
+
+    let training = match core.dragon_training_cost() {
+        Some(DragonTrainingCost::Cookies(cookies)) => format!(
+            "• next training costs {} cookies",
+            cookies.print_float(0, 0)
+        ),
+        Some(DragonTrainingCost::Building(building, count)) => format!(
+            "• next training sacrifices {count} {}",
+            building.name_lower_pluralized(count as _)
+        ),
+        Some(DragonTrainingCost::AllBuildings(count)) => {
+            format!("• next training sacrifices {count} of every building")
+        }
+        None => "• krumblor is fully trained".to_owned(),
+    };
+
+    vec![Line::from(slots), Line::raw(training)]
+}
+
+struct AuraWidget<'a> {
+    core: &'a Core,
+    aura: DragonAura,
+    selected: bool,
+}
+
+impl Widget for AuraWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let dragon = self.core.dragon();
+
+        let mut name = vec![Span::styled(
+            self.aura.name(),
+            Style::new()
+                .bold()
+                .fg_if(!dragon.aura_unlocked(self.aura), Color::DarkGray)
+                .selected_if(self.selected),
+        )];
+        if let Some(slot) = dragon.slot_of(self.aura) {
+            name.push(Span::styled(
+                format!(" ({})", slot.name_lower()),
+                Style::new().light_green(),
+            ));
+        } else if !dragon.aura_unlocked(self.aura) {
+            name.push(Span::raw(format!(" (level {})", self.aura.level())));
+        }
+
+        let effect = Line::raw(format!("  {}", effect(self.aura))).dark_gray();
+
+        Paragraph::new(vec![Line::from(name), effect]).render(area, buf);
+    }
+}
+
+fn effect(aura: DragonAura) -> &'static str {
+    match aura {
+        DragonAura::BreathOfMilk => "kittens are 5% more effective",
+        DragonAura::DragonCursor => "click buffs are 5% more powerful",
+        DragonAura::ElderBattalion => "grandmas gain +1% cps for every non-grandma building",
+        DragonAura::RadiantAppetite => "all cookie production multiplied by 2",
+    }
+}
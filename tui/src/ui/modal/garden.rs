@@ -0,0 +1,143 @@
+use super::ModalImpl;
+use crate::{
+    app::AppModalState,
+    ui::{
+        UiApp,
+        utils::{num::PrintFloat, style::StyleExt},
+    },
+};
+use cookie_clicker_tui_core::{Core, CostResolved, Plant, Seed};
+use cookie_clicker_tui_utils::str::duration;
+use ratatui::{prelude::*, widgets::Paragraph};
+
+const TILE_WIDTH: u16 = 6;
+const TILE_HEIGHT: u16 = 3;
+
+pub fn garden(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: " Garden ".into(),
+        title_bottom: " Plant <P> Harvest <X> Seed <Tab> Soil <O> Close <Esc> ".into(),
+        screen_percent: (60, 60),
+    };
+
+    modal.render(|area, buf, block| {
+        let (cursor_x, cursor_y, seed) = match app.modal {
+            AppModalState::Garden { x, y, seed } => (*x, *y, *seed),
+            _ => unreachable!(),
+        };
+        let (width, height) = app.core.garden_plot_size();
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let cols = Layout::horizontal([
+            Constraint::Length(TILE_WIDTH * width as u16),
+            Constraint::Fill(1),
+        ])
+        .spacing(2)
+        .split(inner);
+
+        for y in 0..height {
+            for x in 0..width {
+                let tile = Rect {
+                    x: cols[0].x + x as u16 * TILE_WIDTH,
+                    y: cols[0].y + y as u16 * TILE_HEIGHT,
+                    width: TILE_WIDTH,
+                    height: TILE_HEIGHT,
+                }
+                .intersection(cols[0]);
+
+                TileWidget {
+                    plant: app.core.garden().plant(x, y),
+                    selected: (x, y) == (cursor_x, cursor_y),
+                }
+                .render(tile, buf);
+            }
+        }
+
+        let plant = app.core.garden().plant(cursor_x, cursor_y);
+        Paragraph::new(info_lines(app.core, seed, plant)).render(cols[1], buf);
+    });
+}
+
+fn info_lines(core: &Core, seed: Seed, plant: Option<Plant>) -> Vec<Line<'static>> {
+    let garden = core.garden();
+    let CostResolved::Cookies(cost) = core.garden_seed_cost(seed) else {
+        unreachable!("seeds only cost cookies")
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw("• seed: "),
+            Span::styled(seed.name(), Modifier::BOLD),
+            Span::raw(format!(" ({} cookies)", cost.print_float(0, 1))),
+        ]),
+        Line::from(vec![
+            Span::raw("• soil: "),
+            Span::styled(garden.soil().name(), Modifier::BOLD),
+        ]),
+        Line::raw(format!(
+            "• next tick in {}",
+            duration(garden.secs_until_tick())
+        )),
+        Line::raw(format!(
+            "• cps {}, cpc {}",
+            percent(garden.cps_mult()),
+            percent(garden.cpc_mult())
+        )),
+        Line::raw(format!(
+            "• {}/{} seeds unlocked",
+            garden.seeds().len(),
+            Seed::VARIANT_COUNT
+        )),
+    ];
+
+    if let Some(plant) = plant {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::raw("• "),
+            Span::styled(plant.seed().name(), Modifier::BOLD),
+            Span::raw(format!(
+                " ({}%, {})",
+                plant.age(),
+                if plant.mature() { "mature" } else { "growing" }
+            )),
+        ]));
+    }
+
+    lines
+}
+
+fn percent(mult: f64) -> String {
+    format!("+{}%", ((mult - 1.0) * 100.0).print_float(0, 1))
+}
+
+struct TileWidget {
+    plant: Option<Plant>,
+    selected: bool,
+}
+
+impl Widget for TileWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (text, style) = match self.plant {
+            None => ("·".into(), Style::new().dark_gray()),
+            Some(plant) => {
+                let initials = plant.seed().name().chars().take(2).collect::<String>();
+                let style = if plant.mature() {
+                    Style::new().light_green().bold()
+                } else {
+                    Style::new().green()
+                };
+                (initials, style)
+            }
+        };
+
+        let style = style.selected_if(self.selected);
+
+        Paragraph::new(vec![Line::raw(""), Line::raw(text).centered()])
+            .style(style)
+            .render(area, buf);
+    }
+}
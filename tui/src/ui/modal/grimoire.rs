@@ -0,0 +1,75 @@
+use super::ModalImpl;
+use crate::ui::{UiApp, utils::num::PrintFloat};
+use cookie_clicker_tui_core::Spell;
+use ratatui::{
+    prelude::*,
+    widgets::{Gauge, Paragraph},
+};
+
+pub fn grimoire(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: " Grimoire ".into(),
+        title_bottom: " Cast <1-4> Close <Esc> ".into(),
+        screen_percent: (50, 40),
+    };
+
+    modal.render(|area, buf, block| {
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let rows = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
+            .spacing(1)
+            .split(inner);
+
+        let magic = app.core.grimoire().magic();
+        let max_magic = app.core.grimoire_max_magic();
+        let ratio = if max_magic > 0.0 {
+            (magic / max_magic).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        Gauge::default()
+            .ratio(ratio)
+            .label(format!(
+                "{}/{} magic",
+                magic.floor().print_float(0, 0),
+                max_magic.print_float(0, 0)
+            ))
+            .gauge_style(Style::new().magenta().on_black())
+            .render(rows[0], buf);
+
+        let lines = Spell::variants()
+            .enumerate()
+            .flat_map(|(i, spell)| {
+                let cost = app.core.spell_cost(spell);
+                let style = if cost <= magic {
+                    Style::new().bold()
+                } else {
+                    Style::new().dark_gray()
+                };
+
+                [
+                    Line::from(vec![
+                        Span::styled(format!("<{}> {}", i + 1, spell.name()), style),
+                        Span::raw(format!(" ({} magic)", cost.print_float(0, 0))),
+                    ]),
+                    Line::raw(format!("    {}", description(spell))).dark_gray(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        Paragraph::new(lines).render(rows[1], buf);
+    });
+}
+
+fn description(spell: Spell) -> &'static str {
+    match spell {
+        Spell::ConjureBakedGoods => "summon 30 minutes of cps, up to 15% of your bank",
+        Spell::ForceTheHandOfFate => "summon a golden cookie",
+        Spell::StretchTime => "all active buffs last 10% longer",
+        Spell::HagglersCharm => "upgrades are 2% cheaper for an hour",
+    }
+}
@@ -0,0 +1,142 @@
+use super::{
+    super::utils::{
+        num::PrintFloat,
+        shop::{ShopItemRender, ShopItemWidget},
+    },
+    ModalImpl,
+};
+use crate::{app::AppModalState, ui::UiApp};
+use cookie_clicker_tui_core::{Core, CostResolved, HeavenlyUpgrade};
+use ratatui::{prelude::*, widgets::Paragraph};
+use std::borrow::Cow;
+use tui_widget_list::{ListBuilder, ListView};
+
+pub fn heavenly_upgrades(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    let title = format!(
+        " Heavenly Upgrades ({} heavenly chips) ",
+        app.core.prestige().heavenly_chips().print_float(0, 0)
+    );
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: title.into(),
+        title_bottom: " Buy <Enter> Close <Esc> ".into(),
+        screen_percent: (50, 50),
+    };
+
+    modal.render(|area, buf, block| {
+        let list_state = match app.modal {
+            AppModalState::HeavenlyUpgrades { state } => state,
+            _ => unreachable!(),
+        };
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let rows = Layout::vertical([Constraint::Fill(1), Constraint::Length(3)])
+            .spacing(1)
+            .split(inner);
+
+        let builder = ListBuilder::new(|ctx| {
+            let upgrade = HeavenlyUpgrade::VARIANTS[ctx.index];
+            let affordable =
+                app.core.heavenly_upgrade_unlocked(upgrade) && app.core.affordable(upgrade.cost());
+
+            let item = HeavenlyUpgradeShopItem {
+                core: app.core,
+                upgrade,
+            };
+
+            let widget = ShopItemWidget {
+                selected: ctx.is_selected,
+                affordable,
+                item,
+            };
+
+            (widget, ShopItemWidget::HEIGHT)
+        });
+
+        ListView::new(builder, HeavenlyUpgrade::VARIANT_COUNT).render(rows[0], buf, list_state);
+
+        if let Some(upgrade) = list_state
+            .selected
+            .and_then(|i| HeavenlyUpgrade::VARIANTS.get(i).copied())
+        {
+            Paragraph::new(info_lines(app.core, upgrade)).render(rows[1], buf);
+        }
+    });
+}
+
+fn info_lines(core: &Core, upgrade: HeavenlyUpgrade) -> Vec<Line<'static>> {
+    let mut lines = vec![match upgrade {
+        HeavenlyUpgrade::Legacy => Line::raw("• unlocks the heavenly upgrades tree"),
+        HeavenlyUpgrade::HeavenlyCookies => Line::from(vec![
+            Span::raw("• "),
+            Span::styled("+10%", Modifier::BOLD),
+            Span::raw(" cookies per second permanently"),
+        ]),
+        HeavenlyUpgrade::HowToBakeYourDragon => {
+            Line::raw("• allows you to bake a dragon companion")
+        }
+        HeavenlyUpgrade::SeasonSwitcher => {
+            Line::raw("• allows you to trigger seasonal events at will")
+        }
+        HeavenlyUpgrade::StarterKit => Line::from(vec![
+            Span::raw("• you start with "),
+            Span::styled("10 cursors", Modifier::BOLD),
+            Span::raw(" after ascending"),
+        ]),
+        HeavenlyUpgrade::StarterKitchen => Line::from(vec![
+            Span::raw("• you start with "),
+            Span::styled("5 grandmas", Modifier::BOLD),
+            Span::raw(" after ascending"),
+        ]),
+        HeavenlyUpgrade::PermanentUpgradeSlotI
+        | HeavenlyUpgrade::PermanentUpgradeSlotII
+        | HeavenlyUpgrade::PermanentUpgradeSlotIII
+        | HeavenlyUpgrade::PermanentUpgradeSlotIV
+        | HeavenlyUpgrade::PermanentUpgradeSlotV => Line::from(vec![
+            Span::raw("• keeps your "),
+            Span::styled("most expensive", Modifier::BOLD),
+            Span::raw(" upgrade not otherwise kept when ascending"),
+        ]),
+    }];
+
+    if !core.heavenly_upgrade_unlocked(upgrade) {
+        let parents = upgrade
+            .parents()
+            .iter()
+            .map(|p| p.name_lower())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        lines.push(Line::from(vec![
+            Span::raw("• requires "),
+            Span::styled(parents, Modifier::BOLD),
+        ]));
+    }
+
+    lines
+}
+
+struct HeavenlyUpgradeShopItem<'a> {
+    core: &'a Core,
+    upgrade: HeavenlyUpgrade,
+}
+
+impl ShopItemRender for HeavenlyUpgradeShopItem<'_> {
+    fn label(&self) -> Cow<'static, str> {
+        self.upgrade.name().into()
+    }
+
+    fn cost(&self) -> CostResolved {
+        self.core.resolve_cost(self.upgrade.cost())
+    }
+
+    fn emoji(&self) -> Option<(&str, Style)> {
+        self.core
+            .owned_heavenly_upgrades()
+            .contains(&self.upgrade)
+            .then(|| (" ✓", Style::new().light_green()))
+    }
+}
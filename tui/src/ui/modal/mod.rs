@@ -1,5 +1,13 @@
+mod ascend;
 mod building;
+mod dragon;
+mod garden;
+mod grimoire;
+mod heavenly_upgrades;
+mod offline;
+mod pantheon;
 mod rename_bakery;
+mod stock_market;
 mod upgrade;
 mod wrinklers;
 
@@ -23,6 +31,16 @@ pub fn modal(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
         },
         AppModalState::RenamingBakery(name) => rename_bakery::rename_bakery(name, area, buf),
         AppModalState::Wrinklers { .. } => wrinklers::wrinklers(app, area, buf),
+        AppModalState::Ascending => ascend::ascend(app, area, buf),
+        AppModalState::Offline(progress) => offline::offline(*progress, area, buf),
+        AppModalState::HeavenlyUpgrades { .. } => {
+            heavenly_upgrades::heavenly_upgrades(app, area, buf)
+        }
+        AppModalState::StockMarket { .. } => stock_market::stock_market(app, area, buf),
+        AppModalState::Grimoire => grimoire::grimoire(app, area, buf),
+        AppModalState::Pantheon { .. } => pantheon::pantheon(app, area, buf),
+        AppModalState::Garden { .. } => garden::garden(app, area, buf),
+        AppModalState::Dragon { .. } => dragon::dragon(app, area, buf),
     }
 }
 
@@ -0,0 +1,45 @@
+use super::{super::utils::num::PrintFloat, ModalImpl};
+use cookie_clicker_tui_core::OfflineProgress;
+use cookie_clicker_tui_utils::str::{duration, pluralized};
+use ratatui::{prelude::*, widgets::Paragraph};
+
+pub fn offline(progress: OfflineProgress, area: Rect, buf: &mut Buffer) {
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: " While You Were Away ".into(),
+        title_bottom: " Close <Esc> ".into(),
+        screen_percent: (50, 25),
+    };
+
+    modal.render(|area, buf, block| {
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw("• you were away for "),
+                Span::styled(format!("{}", duration(progress.secs)), Modifier::BOLD),
+            ]),
+            Line::from(vec![
+                Span::raw("• your bakery made "),
+                Span::styled(
+                    format!("{}", progress.cookies.print_float(0, 2)),
+                    Modifier::BOLD,
+                ),
+                Span::raw(" cookies"),
+            ]),
+        ];
+
+        if progress.sugar_lumps > 0 {
+            lines.push(Line::from(vec![
+                Span::raw("• "),
+                Span::styled(format!("{}", progress.sugar_lumps), Modifier::BOLD),
+                Span::raw(pluralized(
+                    progress.sugar_lumps as _,
+                    " sugar lump grew",
+                    " sugar lumps grew",
+                )),
+            ]));
+        }
+
+        Paragraph::new(lines).block(block).render(area, buf);
+    });
+}
@@ -0,0 +1,123 @@
+use super::ModalImpl;
+use crate::{
+    app::AppModalState,
+    ui::{UiApp, utils::style::StyleExt},
+};
+use cookie_clicker_tui_core::{Core, Spirit, SpiritSlot};
+use cookie_clicker_tui_utils::str::duration;
+use ratatui::{prelude::*, widgets::Paragraph};
+use tui_widget_list::{ListBuilder, ListView};
+
+pub fn pantheon(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: " Pantheon ".into(),
+        title_bottom: " Diamond <1> Ruby <2> Jade <3> Unslot <U> Close <Esc> ".into(),
+        screen_percent: (50, 50),
+    };
+
+    modal.render(|area, buf, block| {
+        let list_state = match app.modal {
+            AppModalState::Pantheon { state } => state,
+            _ => unreachable!(),
+        };
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let rows = Layout::vertical([Constraint::Length(2), Constraint::Fill(1)])
+            .spacing(1)
+            .split(inner);
+
+        Paragraph::new(header_lines(app.core)).render(rows[0], buf);
+
+        let builder = ListBuilder::new(|ctx| {
+            let widget = SpiritWidget {
+                core: app.core,
+                spirit: Spirit::VARIANTS[ctx.index],
+                selected: ctx.is_selected,
+            };
+
+            const HEIGHT: u16 = 2;
+
+            (widget, HEIGHT)
+        });
+
+        ListView::new(builder, Spirit::VARIANT_COUNT).render(rows[1], buf, list_state);
+    });
+}
+
+fn header_lines(core: &Core) -> Vec<Line<'static>> {
+    let pantheon = core.pantheon();
+
+    let mut slots = Vec::new();
+    for slot in SpiritSlot::variants() {
+        slots.push(Span::raw(format!("{}: ", slot.name())));
+        slots.push(Span::styled(
+            pantheon.spirit(slot).map_or("—", |s| s.name()),
+            Modifier::BOLD,
+        ));
+        slots.push(Span::raw("  "));
+    }
+
+    let mut swaps = format!(
+        "• {}/{} worship swaps",
+        pantheon.swaps(),
+        pantheon.max_swaps()
+    );
+    if let Some(secs) = pantheon.secs_until_swap() {
+        swaps.push_str(&format!(" (next in {})", duration(secs)));
+    }
+
+    vec![Line::from(slots), Line::raw(swaps)]
+}
+
+struct SpiritWidget<'a> {
+    core: &'a Core,
+    spirit: Spirit,
+    selected: bool,
+}
+
+impl Widget for SpiritWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let slot = self.core.pantheon().slot_of(self.spirit);
+
+        let mut name = vec![
+            Span::styled(
+                self.spirit.name(),
+                Style::new().bold().selected_if(self.selected),
+            ),
+            Span::raw(format!(", {}", self.spirit.title())),
+        ];
+        if let Some(slot) = slot {
+            name.push(Span::styled(
+                format!(" ({})", slot.name_lower()),
+                Style::new().light_green(),
+            ));
+        }
+
+        let effects = Line::raw(format!("  {}", effects(self.spirit))).dark_gray();
+
+        Paragraph::new(vec![Line::from(name), effects]).render(area, buf);
+    }
+}
+
+fn effects(spirit: Spirit) -> String {
+    let mut effects = Vec::new();
+
+    let mut push = |what: &str, mults: [f64; 3]| {
+        if mults.iter().any(|m| *m != 1.0) {
+            let percents = mults
+                .map(|m| format!("{:+.0}%", (m - 1.0) * 100.0))
+                .join("/");
+            effects.push(format!("{what} {percents}"));
+        }
+    };
+
+    push("cps", spirit.cps_mults());
+    push("building costs", spirit.building_cost_mults());
+    push("golden cookie frequency", spirit.golden_cookie_freq_mults());
+
+    effects.join(", ")
+}
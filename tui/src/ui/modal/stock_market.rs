@@ -0,0 +1,126 @@
+use super::ModalImpl;
+use crate::{
+    app::AppModalState,
+    ui::{
+        UiApp,
+        utils::{num::PrintFloat, style::StyleExt},
+    },
+};
+use cookie_clicker_tui_core::{Core, Good, MarketMode};
+use cookie_clicker_tui_utils::str::duration;
+use ratatui::{
+    prelude::*,
+    widgets::{Paragraph, Sparkline},
+};
+use tui_widget_list::{ListBuilder, ListView};
+
+pub fn stock_market(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
+    let title = format!(
+        " Stock Market (next tick in {}) ",
+        duration(app.core.stock_market().secs_until_tick())
+    );
+    let modal = ModalImpl {
+        area,
+        buf,
+        title: title.into(),
+        title_bottom: " Buy <B> Sell <S> Sell All <Shift+S> Close <Esc> ".into(),
+        screen_percent: (70, 60),
+    };
+
+    modal.render(|area, buf, block| {
+        let list_state = match app.modal {
+            AppModalState::StockMarket { state } => state,
+            _ => unreachable!(),
+        };
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let rows = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)])
+            .spacing(1)
+            .split(inner);
+
+        let builder = ListBuilder::new(|ctx| {
+            let widget = GoodWidget {
+                core: app.core,
+                good: Good::VARIANTS[ctx.index],
+                selected: ctx.is_selected,
+            };
+
+            const HEIGHT: u16 = 2;
+
+            (widget, HEIGHT)
+        });
+
+        ListView::new(builder, Good::VARIANT_COUNT).render(rows[0], buf, list_state);
+
+        if let Some(good) = list_state
+            .selected
+            .and_then(|i| Good::VARIANTS.get(i).copied())
+        {
+            Line::raw(format!(
+                "• 1 {} costs {} cookies right now",
+                good.name_lower(),
+                app.core.stock_price_cookies(good).print_float(0, 1)
+            ))
+            .render(rows[1], buf);
+        }
+    });
+}
+
+struct GoodWidget<'a> {
+    core: &'a Core,
+    good: Good,
+    selected: bool,
+}
+
+impl Widget for GoodWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let state = self.core.stock_market().good(self.good);
+
+        let cols = Layout::horizontal([
+            Constraint::Length(12),
+            Constraint::Length(20),
+            Constraint::Length(10),
+            Constraint::Fill(1),
+        ])
+        .spacing(1)
+        .split(area);
+
+        Paragraph::new(self.good.name())
+            .style(Style::new().selected_if(self.selected))
+            .render(cols[0], buf);
+
+        let mode_color = match state.mode() {
+            MarketMode::Stable => Color::Reset,
+            MarketMode::Rising => Color::LightGreen,
+            MarketMode::Falling => Color::LightRed,
+            MarketMode::Chaotic => Color::Yellow,
+        };
+
+        Line::from(vec![
+            Span::styled(format!("${:.2}", state.price()), Modifier::BOLD),
+            Span::raw(" "),
+            Span::styled(state.mode().name_lower(), mode_color),
+        ])
+        .render(cols[1], buf);
+
+        Line::raw(format!(
+            "{}/{}",
+            state.owned(),
+            self.core.stock_warehouse_limit(self.good)
+        ))
+        .render(cols[2], buf);
+
+        let min = state.history().fold(f64::INFINITY, f64::min);
+        let data = state
+            .history()
+            .map(|p| ((p - min) * 100.0) as u64)
+            .collect::<Vec<_>>();
+
+        Sparkline::default()
+            .data(&data)
+            .style(Style::new().fg(mode_color))
+            .render(cols[3], buf);
+    }
+}
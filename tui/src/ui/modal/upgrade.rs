@@ -1,5 +1,5 @@
 use super::{super::utils::num::PrintFloat, ModalImpl};
-use cookie_clicker_tui_core::{Building, Upgrade, UpgradeEffectInfo};
+use cookie_clicker_tui_core::{Building, Upgrade, UpgradeEffectInfo, UpgradeInfoEffectSantaGift};
 use ratatui::{prelude::*, widgets::Paragraph};
 
 pub fn upgrade(upgrade: Upgrade, area: Rect, buf: &mut Buffer) {
@@ -64,6 +64,38 @@ pub fn print_info(info: UpgradeEffectInfo, lines: &mut Vec<Line>) {
                 Span::raw(" you have"),
             ]));
         }
+        UpgradeEffectInfo::Synergy {
+            lower,
+            higher,
+            lower_mult_per,
+            higher_mult_per,
+        } => {
+            // rounded so that float noise doesn't show up as an extra decimal
+            let percent = |mult: f64| ((mult - 1.0) * 1000.0).round() / 10.0;
+
+            lines.push(Line::from(vec![
+                Span::raw("• "),
+                Span::styled(lower.name_lower_plural(), Modifier::BOLD),
+                Span::raw(" gain "),
+                Span::styled(
+                    format!("+{}%", percent(lower_mult_per).print_float(1, 0)),
+                    Modifier::BOLD,
+                ),
+                Span::raw(" cookies per second per "),
+                Span::styled(higher.name_lower(), Modifier::BOLD),
+            ]));
+            lines.push(Line::from(vec![
+                Span::raw("• "),
+                Span::styled(higher.name_lower_plural(), Modifier::BOLD),
+                Span::raw(" gain "),
+                Span::styled(
+                    format!("+{}%", percent(higher_mult_per).print_float(1, 0)),
+                    Modifier::BOLD,
+                ),
+                Span::raw(" cookies per second per "),
+                Span::styled(lower.name_lower(), Modifier::BOLD),
+            ]));
+        }
         UpgradeEffectInfo::Research { effect, warning } => {
             use cookie_clicker_tui_core::{
                 UpgradeInfoEffectResearch as R, UpgradeInfoEffectResearchWarning as W,
@@ -146,6 +178,102 @@ pub fn print_info(info: UpgradeEffectInfo, lines: &mut Vec<Line>) {
                 Span::raw(" cookies per second"),
             ]));
         }
+        UpgradeEffectInfo::GoldenCookieFrequency(mult) => {
+            lines.push(Line::from(vec![
+                Span::raw("• golden cookies appear "),
+                Span::styled(format!("{}x", mult.print_float(0, 0)), Modifier::BOLD),
+                Span::raw(" as often"),
+            ]));
+            lines.push(Line::from(vec![
+                Span::raw("• golden cookies stay "),
+                Span::styled(format!("{}x", mult.print_float(0, 0)), Modifier::BOLD),
+                Span::raw(" as long"),
+            ]));
+        }
+        UpgradeEffectInfo::GoldenCookieEffectDuration(mult) => {
+            lines.push(Line::from(vec![
+                Span::raw("• golden cookie effects last "),
+                Span::styled(format!("{}x", mult.print_float(0, 0)), Modifier::BOLD),
+                Span::raw(" as long"),
+            ]));
+        }
+        UpgradeEffectInfo::WrinklerMaxSize(n) => {
+            lines.push(Line::from(vec![
+                Span::raw("• you can attract "),
+                Span::styled(format!("{n}"), Modifier::BOLD),
+                Span::raw(" more wrinklers"),
+            ]));
+        }
+        UpgradeEffectInfo::WrinklerPopMult(mult) => {
+            lines.push(Line::from(vec![
+                Span::raw("• wrinklers explode into "),
+                Span::styled(
+                    format!("{}%", ((mult - 1.0) * 100.0).print_float(0, 0)),
+                    Modifier::BOLD,
+                ),
+                Span::raw(" more cookies"),
+            ]));
+        }
+        UpgradeEffectInfo::SeasonSwitcher(season) => {
+            lines.push(Line::from(vec![
+                Span::raw("• triggers "),
+                Span::styled(season.name(), Modifier::BOLD),
+                Span::raw(" for the next 24 hours"),
+            ]));
+            lines.push(Line::raw(
+                "• cost scales with cookies per second and each season switch",
+            ));
+        }
+        UpgradeEffectInfo::SeasonDrop { season, mult } => {
+            lines.push(Line::from(vec![
+                Span::raw("• cookie production multiplier "),
+                Span::styled(
+                    format!("+{}%", ((mult - 1.0) * 100.0).print_float(0, 0)),
+                    Modifier::BOLD,
+                ),
+            ]));
+            lines.push(Line::from(vec![
+                Span::raw("• found during "),
+                Span::styled(season.name(), Modifier::BOLD),
+            ]));
+        }
+        UpgradeEffectInfo::SantaGift(effect) => {
+            match effect {
+                UpgradeInfoEffectSantaGift::CpsMult(mult) => {
+                    lines.push(Line::from(vec![
+                        Span::raw("• cookie production multiplier "),
+                        Span::styled(
+                            format!("+{}%", ((mult - 1.0) * 100.0).print_float(0, 0)),
+                            Modifier::BOLD,
+                        ),
+                    ]));
+                }
+                UpgradeInfoEffectSantaGift::CpsMultPerSantaLevel(mult) => {
+                    lines.push(Line::from(vec![
+                        Span::raw("• cookie production multiplier "),
+                        Span::styled(
+                            format!("+{}%", (mult * 100.0).print_float(0, 0)),
+                            Modifier::BOLD,
+                        ),
+                        Span::raw(" per Santa level"),
+                    ]));
+                }
+                UpgradeInfoEffectSantaGift::ReindeerSpawnMult(mult) => {
+                    lines.push(Line::from(vec![
+                        Span::raw("• reindeer appear "),
+                        Span::styled(
+                            format!("{}x", (1.0 / mult).print_float(0, 0)),
+                            Modifier::BOLD,
+                        ),
+                        Span::raw(" as often"),
+                    ]));
+                }
+            }
+            lines.push(Line::raw("• a gift from Santa"));
+        }
+        UpgradeEffectInfo::DragonEgg => {
+            lines.push(Line::raw("• a dragon egg, ready to be trained"));
+        }
     }
 }
 
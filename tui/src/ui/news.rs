@@ -319,5 +319,33 @@ fn line_text(entry: E, bakery_name: Option<&str>) -> Line<'static> {
             6 => gquote!("We are not satiated."),
             7 => gquote!("Too late."),
         },
+        /* -------------------------------------------------------------------------- */
+        /*                                Season Based                                */
+        /* -------------------------------------------------------------------------- */
+        E::Season_BusinessDay => many! {
+            0 => raw!("News: cookie company ceos cheer as markets open on business day!"),
+            1 => raw!("News: \"synergize your cookie pipeline\", urges motivational speaker to empty auditorium."),
+            2 => raw!("News: local bakeries switch to quarterly cookie reports."),
+        },
+        E::Season_Christmas => many! {
+            0 => raw!("News: reindeer sightings on the rise, experts baffled!"),
+            1 => raw!("News: elves stage walkout over unpaid cookie breaks."),
+            2 => raw!("News: Santa spotted hoarding biscuits in undisclosed location!"),
+        },
+        E::Season_Easter => many! {
+            0 => raw!("News: egg hunts turn violent as bakers race for rare specimens!"),
+            1 => raw!("News: bunnies seen hiding eggs in cookie factories, authorities shrug."),
+            2 => raw!("News: scientists confirm cookies can, in fact, hatch."),
+        },
+        E::Season_Halloween => many! {
+            0 => raw!("News: wrinklers spotted wearing tiny costumes!"),
+            1 => raw!("News: spooky cookies reported to rattle in their jars at night."),
+            2 => gquote!("Trick or treat."),
+        },
+        E::Season_Valentines => many! {
+            0 => raw!("News: heart-shaped biscuits outsell flowers for the first time in history!"),
+            1 => raw!("News: study finds cookies are the love language of choice."),
+            2 => gquote!("Love you, sweetie."),
+        },
     }
 }
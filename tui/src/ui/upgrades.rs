@@ -3,7 +3,7 @@ use super::{
     utils::shop::{ShopItemRender, ShopItemWidget},
 };
 use crate::app::AppListPane;
-use cookie_clicker_tui_core::{Core, CostResolved, Upgrade};
+use cookie_clicker_tui_core::{Core, CostResolved, Purchase, Upgrade};
 use ratatui::{
     prelude::*,
     widgets::{Block, Padding},
@@ -18,7 +18,7 @@ pub fn upgrades(app: &mut UiApp, area: Rect, buf: &mut Buffer) {
     let builder = ListBuilder::new(|ctx| {
         let selected = list_selected && ctx.is_selected;
         let upgrade = upgrades[ctx.index];
-        let affordable = app.core.affordable(upgrade.cost());
+        let affordable = app.core.affordable_resolved(app.core.upgrade_cost(upgrade));
 
         let item = UpgradeShopItem {
             core: app.core,
@@ -55,6 +55,10 @@ impl ShopItemRender for UpgradeShopItem<'_> {
     }
 
     fn cost(&self) -> CostResolved {
-        self.core.resolve_cost(self.upgrade.cost())
+        self.core.upgrade_cost(self.upgrade)
+    }
+
+    fn best_buy(&self) -> bool {
+        self.core.best_buy() == Some(Purchase::Upgrade(self.upgrade))
     }
 }
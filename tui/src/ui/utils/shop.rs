@@ -16,6 +16,10 @@ pub trait ShopItemRender {
     fn emoji(&self) -> Option<(&str, Style)> {
         None
     }
+
+    fn best_buy(&self) -> bool {
+        false
+    }
 }
 
 impl ShopItemWidget<()> {
@@ -40,6 +44,10 @@ impl<T: ShopItemRender> ShopItemWidget<T> {
             v.push(Span::styled(emoji, emoji_style));
         }
 
+        if self.item.best_buy() {
+            v.push(Span::styled(" ★ best buy", Style::new().green().bold()));
+        }
+
         Line::from(v).style(
             Style::new()
                 .selected_if(self.selected)
@@ -49,12 +57,11 @@ impl<T: ShopItemRender> ShopItemWidget<T> {
 
     fn cost_line(&self) -> Line {
         Line::styled(
-            format!(
-                "{} $c",
-                match self.item.cost() {
-                    CostResolved::Cookies(c) => c.print_float(0, 0),
-                }
-            ),
+            match self.item.cost() {
+                CostResolved::Cookies(c) => format!("{} $c", c.print_float(0, 0)),
+                CostResolved::HeavenlyChips(c) => format!("{} $hc", c.print_float(0, 0)),
+                CostResolved::SugarLumps(c) => format!("{c} $sl"),
+            },
             Style::new()
                 .selected_if(self.selected)
                 .fg_if(!self.affordable, Color::DarkGray)